@@ -0,0 +1,32 @@
+//! Deterministic strategy sampling for benchmark fixtures.
+
+use proptest::strategy::Strategy;
+
+use crate::seed::{draw_values, seeded_runner};
+
+/// Materialize `n` values from `strategy`, fully determined by `seed`.
+///
+/// Lets criterion (or any harness) benchmark against the same domain strategies the property
+/// tests use; build the batch once outside the timed loop.
+pub fn sample_n<S>(strategy: S, n: usize, seed: u64) -> Vec<S::Value>
+where
+    S: Strategy,
+{
+    draw_values(&strategy, &mut seeded_runner(seed), n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::decimals::tick_aligned;
+
+    #[test]
+    fn same_seed_yields_same_batch_of_length_n() {
+        let strategy = tick_aligned(0.01, 100_000.0, 0.01);
+        let first = sample_n(&strategy, 500, 42);
+        assert_eq!(first.len(), 500);
+        assert_eq!(first, sample_n(&strategy, 500, 42));
+        assert_ne!(first, sample_n(&strategy, 500, 43));
+        assert!(sample_n(&strategy, 0, 42).is_empty());
+    }
+}
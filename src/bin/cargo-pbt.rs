@@ -5,10 +5,10 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
 
-use kitchensink_testing::mutation::state::MutantState;
+use kitchensink_testing::mutation::state::{MutantState, RunSnapshot};
 use kitchensink_testing::mutation::{
-    CargoMutantsEngine, MutationConfig, MutationStatus, ReportFormat, RunSummary, load_run_status,
-    render_report, rerun_survivors, resume_run, run_new,
+    CargoMutantsEngine, DryRunEngine, MutationConfig, ReportFormat, RunSummary, bundle_run,
+    load_run_status, render_report, rerun_survivors, resume_run, run_new, run_warnings,
 };
 
 #[derive(Debug, Parser)]
@@ -44,6 +44,27 @@ enum MutateCommand {
         /// Optional timeout hint in seconds.
         #[arg(long)]
         timeout_secs: Option<u64>,
+        /// Workspace member to mutate (repeatable); defaults to every member.
+        #[arg(long = "package")]
+        packages: Vec<String>,
+        /// Extra argument appended to `cargo mutants` (repeatable), e.g. `--cargo-arg=--features=x`.
+        #[arg(long = "cargo-arg", allow_hyphen_values = true)]
+        cargo_args: Vec<String>,
+        /// Arguments after `--` are forwarded to `cargo test`.
+        #[arg(last = true)]
+        test_args: Vec<String>,
+        /// Base URL for report source links, e.g. `https://github.com/owner/repo/blob`.
+        #[arg(long)]
+        source_base_url: Option<String>,
+        /// Discover and filter mutants but mark every one `skipped` instead of running tests.
+        #[arg(long)]
+        dry_run: bool,
+        /// Return immediately when the latest compatible run completed with no survivors or errors.
+        #[arg(long)]
+        reuse_clean_run: bool,
+        /// Exit non-zero when any mutant ended in `error` (tooling failure, not weak tests).
+        #[arg(long)]
+        fail_on_error: bool,
     },
     /// Resume an existing run id.
     Resume {
@@ -58,6 +79,15 @@ enum MutateCommand {
         /// Optional timeout hint in seconds.
         #[arg(long)]
         timeout_secs: Option<u64>,
+        /// Workspace member to mutate (repeatable); defaults to every member.
+        #[arg(long = "package")]
+        packages: Vec<String>,
+        /// Extra argument appended to `cargo mutants` (repeatable), e.g. `--cargo-arg=--features=x`.
+        #[arg(long = "cargo-arg", allow_hyphen_values = true)]
+        cargo_args: Vec<String>,
+        /// Arguments after `--` are forwarded to `cargo test`.
+        #[arg(last = true)]
+        test_args: Vec<String>,
     },
     /// Re-run only survivor mutants from an existing run id.
     Survivors {
@@ -72,6 +102,15 @@ enum MutateCommand {
         /// Optional timeout hint in seconds.
         #[arg(long)]
         timeout_secs: Option<u64>,
+        /// Workspace member to mutate (repeatable); defaults to every member.
+        #[arg(long = "package")]
+        packages: Vec<String>,
+        /// Extra argument appended to `cargo mutants` (repeatable), e.g. `--cargo-arg=--features=x`.
+        #[arg(long = "cargo-arg", allow_hyphen_values = true)]
+        cargo_args: Vec<String>,
+        /// Arguments after `--` are forwarded to `cargo test`.
+        #[arg(last = true)]
+        test_args: Vec<String>,
     },
     /// Show status for run id.
     Status {
@@ -80,6 +119,12 @@ enum MutateCommand {
         /// Run root directory.
         #[arg(long)]
         run_root: Option<PathBuf>,
+        /// Exit non-zero when the event log contains malformed lines.
+        #[arg(long)]
+        strict: bool,
+        /// Exit 0 when clean, 3 with survivors, 4 with errors, 5 while incomplete.
+        #[arg(long)]
+        exit_code: bool,
     },
     /// Render report for run id.
     Report {
@@ -91,6 +136,20 @@ enum MutateCommand {
         /// Run root directory.
         #[arg(long)]
         run_root: Option<PathBuf>,
+        /// Exit non-zero when the event log contains malformed lines.
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Package events, the JSON report, and survivor artifacts into a `.tar.gz`.
+    Bundle {
+        /// Existing run id.
+        run_id: String,
+        /// Output archive path.
+        #[arg(long)]
+        out: PathBuf,
+        /// Run root directory.
+        #[arg(long)]
+        run_root: Option<PathBuf>,
     },
     /// List mutants for run id.
     List {
@@ -127,6 +186,8 @@ enum OutputFormat {
     Md,
     /// JSON
     Json,
+    /// Prometheus/OpenMetrics text
+    Prometheus,
 }
 
 fn make_config(
@@ -134,6 +195,9 @@ fn make_config(
     run_root: Option<PathBuf>,
     filter: Option<String>,
     timeout_secs: Option<u64>,
+    packages: Vec<String>,
+    cargo_args: Vec<String>,
+    test_args: Vec<String>,
 ) -> MutationConfig {
     let mut config = MutationConfig::default();
     if let Some(project) = project {
@@ -149,18 +213,53 @@ fn make_config(
         config = config.with_timeout_secs(timeout_secs);
     }
     config
+        .with_packages(packages)
+        .with_extra_args(cargo_args)
+        .with_test_args(test_args)
+}
+
+fn run_root_config(run_root: Option<PathBuf>) -> MutationConfig {
+    make_config(
+        None,
+        run_root,
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Load a run for display, printing its integrity warnings to stderr once.
+fn load_run_reporting_warnings(config: &MutationConfig, run_id: &str) -> Result<RunSnapshot> {
+    let snapshot = load_run_status(config, run_id)?;
+    for warning in run_warnings(&snapshot) {
+        eprintln!("kitchensink-testing: warning: {warning}");
+    }
+    Ok(snapshot)
+}
+
+/// Exit with status 1 under `--strict` when replay skipped malformed event-log lines.
+fn enforce_strict_log(snapshot: &RunSnapshot, strict: bool) {
+    if strict && snapshot.malformed_lines > 0 {
+        eprintln!(
+            "error: run {} has {} malformed event line(s); the log is corrupt",
+            snapshot.run_id, snapshot.malformed_lines
+        );
+        std::process::exit(1);
+    }
 }
 
-fn status_to_string(status: &MutationStatus) -> &'static str {
-    match status {
-        MutationStatus::Pending => "pending",
-        MutationStatus::Running => "running",
-        MutationStatus::Killed => "killed",
-        MutationStatus::Survived => "survived",
-        MutationStatus::Timeout => "timeout",
-        MutationStatus::Unviable => "unviable",
-        MutationStatus::Skipped => "skipped",
-        MutationStatus::Error => "error",
+/// `--exit-code` mapping for `mutate status`; incomplete wins over errors, errors over survivors.
+fn status_exit_code(snapshot: &RunSnapshot, summary: &RunSummary) -> i32 {
+    if !snapshot.completed || summary.incomplete > 0 {
+        5
+    } else if summary.has_infra_errors() {
+        4
+    } else if summary.survived > 0 {
+        3
+    } else {
+        0
     }
 }
 
@@ -192,7 +291,7 @@ fn inspect_payload(run_id: &str, state: &MutantState, run_dir: &Path) -> serde_j
         "mutant_id": state.spec.id,
         "label": state.spec.label,
         "selector": state.spec.selector,
-        "status": status_to_string(&state.status),
+        "status": state.status.to_string(),
         "started_at_ms": state.started_at_ms,
         "finished_at_ms": state.finished_at_ms,
         "duration_ms": state.duration_ms,
@@ -225,9 +324,33 @@ fn main() -> Result<()> {
                 run_root,
                 filter,
                 timeout_secs,
+                packages,
+                cargo_args,
+                test_args,
+                source_base_url,
+                dry_run,
+                reuse_clean_run,
+                fail_on_error,
             } => {
-                let config = make_config(project, run_root, filter, timeout_secs);
-                let run = run_new(&config, &engine)?;
+                let config = make_config(
+                    project,
+                    run_root,
+                    filter,
+                    timeout_secs,
+                    packages,
+                    cargo_args,
+                    test_args,
+                )
+                .with_reuse_clean_runs(reuse_clean_run);
+                let config = match source_base_url {
+                    Some(url) => config.with_source_base_url(url),
+                    None => config,
+                };
+                let run = if dry_run {
+                    run_new(&config, &DryRunEngine::new(engine))?
+                } else {
+                    run_new(&config, &engine)?
+                };
                 let summary = RunSummary::from_snapshot(&run.snapshot);
                 println!("run id: {}", run.run_id);
                 println!("run dir: {}", run.run_dir.display());
@@ -235,14 +358,32 @@ fn main() -> Result<()> {
                     "summary: killed={}, survived={}, incomplete={}, mutation_score={:.2}%",
                     summary.killed, summary.survived, summary.incomplete, summary.mutation_score
                 );
+                if fail_on_error && summary.has_infra_errors() {
+                    eprintln!(
+                        "error: {} mutant(s) ended in error status; mutation tooling is broken",
+                        summary.error
+                    );
+                    std::process::exit(1);
+                }
             }
             MutateCommand::Resume {
                 run_id,
                 project,
                 run_root,
                 timeout_secs,
+                packages,
+                cargo_args,
+                test_args,
             } => {
-                let config = make_config(project, run_root, None, timeout_secs);
+                let config = make_config(
+                    project,
+                    run_root,
+                    None,
+                    timeout_secs,
+                    packages,
+                    cargo_args,
+                    test_args,
+                );
                 let run = resume_run(&config, &run_id, &engine)?;
                 let summary = RunSummary::from_snapshot(&run.snapshot);
                 println!("run id: {}", run.run_id);
@@ -256,8 +397,19 @@ fn main() -> Result<()> {
                 project,
                 run_root,
                 timeout_secs,
+                packages,
+                cargo_args,
+                test_args,
             } => {
-                let config = make_config(project, run_root, None, timeout_secs);
+                let config = make_config(
+                    project,
+                    run_root,
+                    None,
+                    timeout_secs,
+                    packages,
+                    cargo_args,
+                    test_args,
+                );
                 let run = rerun_survivors(&config, &run_id, &engine)?;
                 let summary = RunSummary::from_snapshot(&run.snapshot);
                 println!("run id: {}", run.run_id);
@@ -267,39 +419,77 @@ fn main() -> Result<()> {
                     summary.killed, summary.survived, summary.incomplete, summary.mutation_score
                 );
             }
-            MutateCommand::Status { run_id, run_root } => {
-                let config = make_config(None, run_root, None, None);
-                let snapshot = kitchensink_testing::mutation::load_run_status(&config, &run_id)?;
+            MutateCommand::Status {
+                run_id,
+                run_root,
+                strict,
+                exit_code,
+            } => {
+                let config = run_root_config(run_root);
+                let snapshot = load_run_reporting_warnings(&config, &run_id)?;
                 let summary = RunSummary::from_snapshot(&snapshot);
                 println!("run id: {}", snapshot.run_id);
                 println!("completed: {}", snapshot.completed);
                 println!("interrupted: {}", snapshot.interrupted);
+                println!("malformed lines: {}", snapshot.malformed_lines);
                 println!(
                     "summary: killed={}, survived={}, incomplete={}, mutation_score={:.2}%",
                     summary.killed, summary.survived, summary.incomplete, summary.mutation_score
                 );
+                enforce_strict_log(&snapshot, strict);
+                if exit_code {
+                    std::process::exit(status_exit_code(&snapshot, &summary));
+                }
             }
             MutateCommand::Report {
                 run_id,
                 format,
                 run_root,
+                strict,
             } => {
-                let config = make_config(None, run_root, None, None);
+                let config = run_root_config(run_root);
                 let format = match format {
                     OutputFormat::Md => ReportFormat::Markdown,
                     OutputFormat::Json => ReportFormat::Json,
+                    OutputFormat::Prometheus => ReportFormat::Prometheus,
                 };
-                let snapshot = kitchensink_testing::mutation::load_run_status(&config, &run_id)?;
-                println!("{}", render_report(&snapshot, format));
+                let snapshot = load_run_reporting_warnings(&config, &run_id)?;
+                let report = render_report(&snapshot, format);
+                // The OpenMetrics exposition must end exactly at `# EOF\n`.
+                if format == ReportFormat::Prometheus {
+                    print!("{report}");
+                } else {
+                    println!("{report}");
+                }
+                // stderr keeps machine-readable report output on stdout intact.
+                eprintln!("malformed lines: {}", snapshot.malformed_lines);
+                enforce_strict_log(&snapshot, strict);
+            }
+            MutateCommand::Bundle {
+                run_id,
+                out,
+                run_root,
+            } => {
+                let config = run_root_config(run_root);
+                let (entries, missing) = bundle_run(&config, &run_id, &out)?;
+                for path in missing {
+                    eprintln!(
+                        "kitchensink-testing: warning: survivor artifact {path} is missing; not bundled"
+                    );
+                }
+                println!("bundle: {} ({} file(s))", out.display(), entries.len());
+                for entry in entries {
+                    println!("  {entry}");
+                }
             }
             MutateCommand::List {
                 run_id,
                 json,
                 run_root,
             } => {
-                let config = make_config(None, run_root, None, None);
+                let config = run_root_config(run_root);
                 let run_dir = config.run_root.join(&run_id);
-                let snapshot = load_run_status(&config, &run_id)?;
+                let snapshot = load_run_reporting_warnings(&config, &run_id)?;
                 if json {
                     let mutants: Vec<_> = snapshot
                         .mutants
@@ -329,9 +519,9 @@ fn main() -> Result<()> {
                 log,
                 run_root,
             } => {
-                let config = make_config(None, run_root, None, None);
+                let config = run_root_config(run_root);
                 let run_dir = config.run_root.join(&run_id);
-                let snapshot = load_run_status(&config, &run_id)?;
+                let snapshot = load_run_reporting_warnings(&config, &run_id)?;
                 match snapshot.mutants.get(&mutant_id) {
                     Some(state) => {
                         if json || log {
@@ -100,6 +100,48 @@ where
     attempts
 }
 
+/// Assert concurrent retrying operations collectively stayed within a shared attempt budget.
+///
+/// `total_attempts_observed` is the sum of attempts across all operations that drew from the
+/// same budget. Per-operation budgets let a thundering herd multiply the total; this reports
+/// the overshoot.
+pub fn assert_shared_retry_budget(total_attempts_observed: usize, budget: usize) {
+    assert!(
+        total_attempts_observed <= budget,
+        "shared retry budget overshot by {}: {} attempts observed against a budget of {}",
+        total_attempts_observed - budget,
+        total_attempts_observed,
+        budget
+    );
+}
+
+/// Assert a handler treats a repeated request idempotently, as a safe client retry requires.
+///
+/// Sends `req` twice; both responses must be equal and `effect_count` (e.g. charges or rows
+/// written) may grow by at most one across the pair, meaning the idempotency key was honored.
+/// Returns the response.
+pub fn assert_idempotent_request<Req, Resp, H, C>(req: Req, handler: H, effect_count: C) -> Resp
+where
+    Req: Clone,
+    Resp: PartialEq + Debug,
+    H: Fn(Req) -> Resp,
+    C: Fn() -> usize,
+{
+    let before = effect_count();
+    let first = handler(req.clone());
+    let second = handler(req);
+    let effects = effect_count().saturating_sub(before);
+    assert!(
+        first == second,
+        "repeated request got a different response: {first:?} then {second:?}"
+    );
+    assert!(
+        effects <= 1,
+        "repeated request applied its side effect {effects} times; expected at most once"
+    );
+    first
+}
+
 /// Law-like wrapper for retry-until-success checks.
 pub struct RetryEventuallySucceedsLaw<Op, IsRetryable, T, E> {
     /// Maximum number of attempts allowed.
@@ -238,3 +280,90 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use super::*;
+
+    /// Always-failing operations retried by `workers` threads, each up to `per_op_limit` times,
+    /// optionally gated by a shared budget. Returns total attempts made.
+    fn herd(workers: usize, per_op_limit: usize, shared_budget: Option<usize>) -> usize {
+        let remaining = AtomicUsize::new(shared_budget.unwrap_or(usize::MAX));
+        let attempts = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    for _ in 0..per_op_limit {
+                        let granted = remaining
+                            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                                left.checked_sub(1)
+                            })
+                            .is_ok();
+                        if !granted {
+                            break;
+                        }
+                        attempts.fetch_add(1, Ordering::SeqCst);
+                    }
+                });
+            }
+        });
+        attempts.into_inner()
+    }
+
+    #[test]
+    fn shared_budget_caps_concurrent_retries() {
+        assert_shared_retry_budget(herd(8, 5, Some(10)), 10);
+        assert_shared_retry_budget(0, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "shared retry budget overshot by 30: 40 attempts observed against a budget of 10"
+    )]
+    fn per_operation_budgets_overshoot() {
+        assert_shared_retry_budget(herd(8, 5, None), 10);
+    }
+
+    #[derive(Clone)]
+    struct Charge {
+        idempotency_key: &'static str,
+        cents: u64,
+    }
+
+    #[test]
+    fn keyed_charge_is_applied_once() {
+        let charges: RefCell<HashMap<&str, u64>> = RefCell::new(HashMap::new());
+        let handler = |req: Charge| {
+            *charges
+                .borrow_mut()
+                .entry(req.idempotency_key)
+                .or_insert(req.cents)
+        };
+        let charge = Charge {
+            idempotency_key: "order-7",
+            cents: 1_250,
+        };
+        let receipt = assert_idempotent_request(charge, handler, || charges.borrow().len());
+        assert_eq!(receipt, 1_250);
+    }
+
+    #[test]
+    #[should_panic(expected = "repeated request applied its side effect 2 times")]
+    fn double_charge_is_reported() {
+        let ledger: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+        let handler = |req: Charge| {
+            ledger.borrow_mut().push(req.cents);
+            "ok"
+        };
+        let charge = Charge {
+            idempotency_key: "order-7",
+            cents: 1_250,
+        };
+        assert_idempotent_request(charge, handler, || ledger.borrow().len());
+    }
+}
@@ -0,0 +1,77 @@
+//! Team-wide defaults for proptest runner configuration.
+
+use proptest::test_runner::Config as ProptestConfig;
+
+/// Environment variable overriding the number of cases per property.
+pub const CASES_ENV: &str = "KITCHENSINK_PBT_CASES";
+
+/// Cases per property when running under CI (`CI` set to a truthy value).
+pub const CI_CASES: u32 = 1024;
+
+/// Cases per property for local runs, kept low for a fast edit/test loop.
+pub const LOCAL_CASES: u32 = 64;
+
+/// Shrink iteration budget shared by CI and local runs.
+pub const MAX_SHRINK_ITERS: u32 = 4096;
+
+/// Build a sensibly defaulted `ProptestConfig`.
+///
+/// Uses [`CI_CASES`] when the `CI` environment variable is set and [`LOCAL_CASES`] otherwise;
+/// [`CASES_ENV`] overrides both. Further tuning uses struct update syntax:
+///
+/// ```
+/// use kitchensink_testing::prelude::*;
+///
+/// let config = ProptestConfig { max_shrink_iters: 128, ..pbt_config() };
+/// assert_eq!(config.max_shrink_iters, 128);
+/// ```
+pub fn pbt_config() -> ProptestConfig {
+    config_from_env(|key| std::env::var(key).ok())
+}
+
+fn config_from_env(lookup: impl Fn(&str) -> Option<String>) -> ProptestConfig {
+    let ci = lookup("CI").is_some_and(|value| {
+        let value = value.trim();
+        !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+    });
+    let cases = lookup(CASES_ENV)
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .filter(|cases| *cases > 0)
+        .unwrap_or(if ci { CI_CASES } else { LOCAL_CASES });
+
+    ProptestConfig {
+        cases,
+        max_shrink_iters: MAX_SHRINK_ITERS,
+        ..ProptestConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let pairs: Vec<(String, String)> = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn ci_runs_more_cases_than_local() {
+        assert_eq!(config_from_env(env(&[])).cases, LOCAL_CASES);
+        assert_eq!(config_from_env(env(&[("CI", "true")])).cases, CI_CASES);
+        assert_eq!(config_from_env(env(&[("CI", "false")])).cases, LOCAL_CASES);
+    }
+
+    #[test]
+    fn env_override_wins_over_ci_detection() {
+        let config = config_from_env(env(&[("CI", "1"), (CASES_ENV, "17")]));
+        assert_eq!(config.cases, 17);
+        assert_eq!(config.max_shrink_iters, MAX_SHRINK_ITERS);
+
+        let invalid = config_from_env(env(&[(CASES_ENV, "lots")]));
+        assert_eq!(invalid.cases, LOCAL_CASES);
+    }
+}
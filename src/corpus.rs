@@ -0,0 +1,75 @@
+//! Corpus replay: re-exercise previously failing inputs alongside a base strategy.
+
+use std::fmt::Debug;
+use std::path::Path;
+
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+use serde::de::DeserializeOwned;
+
+/// Build a strategy drawing from the JSON array corpus at `path` about a quarter of the time and
+/// from `base` otherwise.
+///
+/// Complements proptest's per-crate regression files with a corpus that can be shared and
+/// reviewed. A missing file means an empty corpus, so `base` is used alone; an unreadable or
+/// malformed file panics naming the path.
+pub fn from_corpus<T, S>(path: impl AsRef<Path>, base: S) -> BoxedStrategy<T>
+where
+    T: DeserializeOwned + Clone + Debug + 'static,
+    S: Strategy<Value = T> + 'static,
+{
+    let path = path.as_ref();
+    let corpus: Vec<T> = match std::fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).unwrap_or_else(|err| {
+            panic!(
+                "corpus {} is not a JSON array of values: {err}",
+                path.display()
+            )
+        }),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => panic!("corpus {} could not be read: {err}", path.display()),
+    };
+
+    if corpus.is_empty() {
+        return base.boxed();
+    }
+    prop_oneof![1 => proptest::sample::select(corpus), 3 => base].boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::seed::{draw_values, seeded_runner};
+
+    #[test]
+    fn corpus_values_appear_in_samples() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let path = tmp.path().join("corpus.json");
+        std::fs::write(&path, "[1000001, 1000002]").expect("corpus should be written");
+
+        let strategy = from_corpus(&path, 0_u32..10);
+        let samples = draw_values(&strategy, &mut seeded_runner(7), 256);
+        assert!(samples.contains(&1_000_001));
+        assert!(samples.contains(&1_000_002));
+        assert!(samples.iter().any(|v| *v < 10));
+    }
+
+    #[test]
+    fn missing_corpus_falls_back_to_base() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let strategy = from_corpus(tmp.path().join("absent.json"), 0_u32..10);
+        let samples = draw_values(&strategy, &mut seeded_runner(7), 64);
+        assert!(samples.iter().all(|v| *v < 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a JSON array of values")]
+    fn malformed_corpus_panics() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let path = tmp.path().join("corpus.json");
+        std::fs::write(&path, "{\"oops\": true}").expect("corpus should be written");
+        let _ = from_corpus(&path, 0_u32..10);
+    }
+}
@@ -0,0 +1,123 @@
+//! Strategy coverage and distribution assertions.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::seed::draw_values;
+
+/// Assert that `samples` draws from `strategy` hit every bucket in `expected` at least once.
+///
+/// Each drawn value is classified with `bucket`. Draws use proptest's deterministic runner so
+/// the check is reproducible. Panics listing the unhit buckets and the observed counts.
+pub fn assert_covers<S, B, F>(strategy: S, samples: usize, expected: &[B], bucket: F)
+where
+    S: Strategy,
+    B: Eq + Hash + Debug,
+    F: Fn(&S::Value) -> B,
+{
+    let mut runner = TestRunner::deterministic();
+    let mut observed: HashMap<B, usize> = HashMap::new();
+    for value in draw_values(&strategy, &mut runner, samples) {
+        *observed.entry(bucket(&value)).or_default() += 1;
+    }
+
+    let missing: Vec<&B> = expected
+        .iter()
+        .filter(|b| !observed.contains_key(*b))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "strategy did not cover bucket(s) {:?} in {} samples (observed counts: {:?})",
+        missing,
+        samples,
+        observed
+    );
+}
+
+/// Assert that none of `samples` draws from `strategy` satisfies `forbidden`.
+///
+/// The negative counterpart to [`assert_covers`]: validates constraints such as "prices are
+/// never NaN or non-positive". Panics with the first violating sample and its index.
+pub fn assert_strategy_excludes<S, F>(strategy: S, samples: usize, forbidden: F)
+where
+    S: Strategy,
+    S::Value: Debug,
+    F: Fn(&S::Value) -> bool,
+{
+    let mut runner = TestRunner::deterministic();
+    for (index, value) in draw_values(&strategy, &mut runner, samples)
+        .into_iter()
+        .enumerate()
+    {
+        assert!(
+            !forbidden(&value),
+            "strategy produced forbidden value {value:?} at sample {index} of {samples}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    use crate::generators::decimals::tick_aligned;
+    use crate::generators::edge_values::{f64_edge_values, i64_edge_values};
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    enum I64Bucket {
+        Min,
+        Zero,
+        Max,
+        Other,
+    }
+
+    #[test]
+    fn i64_edge_values_hit_bounds_and_zero() {
+        assert_covers(
+            i64_edge_values(),
+            256,
+            &[
+                I64Bucket::Min,
+                I64Bucket::Zero,
+                I64Bucket::Max,
+                I64Bucket::Other,
+            ],
+            |v| match *v {
+                i64::MIN => I64Bucket::Min,
+                0 => I64Bucket::Zero,
+                i64::MAX => I64Bucket::Max,
+                _ => I64Bucket::Other,
+            },
+        );
+    }
+
+    #[test]
+    fn f64_edge_values_produce_nan() {
+        assert_covers(f64_edge_values(), 256, &[true], |v| v.is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "did not cover bucket(s) [\"negative\"]")]
+    fn unreachable_bucket_is_reported() {
+        assert_covers(0_u8..10, 64, &["small", "negative"], |_| "small");
+    }
+
+    #[test]
+    fn prices_exclude_non_positive_and_non_finite() {
+        assert_strategy_excludes(tick_aligned(0.01, 100_000.0, 0.01), 512, |p| {
+            !p.is_finite() || *p <= 0.0
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "strategy produced forbidden value")]
+    fn leaky_strategy_is_reported() {
+        let leaky = prop_oneof![9 => 1.0_f64..100.0, 1 => Just(f64::NAN)];
+        assert_strategy_excludes(leaky, 256, |p| p.is_nan());
+    }
+}
@@ -18,15 +18,179 @@ pub fn tick_aligned(min: f64, max: f64, tick_size: f64) -> impl Strategy<Value =
     (min_ticks..=max_ticks).prop_map(move |t| t as f64 * tick_size)
 }
 
+/// Absolute nudge applied by [`tick_edge_prices`] on top of its one-ULP neighbours.
+pub const TICK_EDGE_EPSILON: f64 = 1e-12;
+
+/// Generate prices on, just above, and just below multiples of `tick_size`.
+///
+/// Unlike [`tick_aligned`], most values are deliberately **not** tick-aligned: each is a
+/// positive multiple `n * tick_size` (up to a million ticks) left exact, moved one ULP either
+/// way, or moved by [`TICK_EDGE_EPSILON`] either way. Use it to stress alignment checks and
+/// rounding code at the boundaries where float error lives.
+///
+/// Where floats near the price are spaced wider than [`TICK_EDGE_EPSILON`] the epsilon move
+/// widens to one ULP, so it is never rounded away. Prices always stay positive: a move below a
+/// tick that would reach zero falls back to the next float below it.
+pub fn tick_edge_prices(tick_size: f64) -> impl Strategy<Value = f64> {
+    assert!(
+        tick_size.is_finite() && tick_size > 0.0,
+        "tick_size must be positive and finite"
+    );
+
+    (1..=1_000_000_i64, 0..5_u8)
+        .prop_map(move |(ticks, edge)| tick_edge(ticks as f64 * tick_size, edge))
+}
+
+fn tick_edge(exact: f64, edge: u8) -> f64 {
+    // The smallest positive float stays put rather than stepping down to zero.
+    let next_down = f64::from_bits((exact.to_bits() - 1).max(1));
+    let nudge = TICK_EDGE_EPSILON.max(ulp(exact));
+    match edge {
+        1 => f64::from_bits(exact.to_bits() + 1),
+        2 => next_down,
+        3 => exact + nudge,
+        4 if exact - nudge > 0.0 => exact - nudge,
+        4 => next_down,
+        _ => exact,
+    }
+}
+
+/// Gap between positive finite `x` and the next float above it.
+fn ulp(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() + 1) - x
+}
+
+/// Generate valid (positive, finite) prices at the edges of `f64` representation.
+///
+/// Draws from three bands: tiny normals just above the subnormal range, huge values up to
+/// `f64::MAX / 1024` (headroom so a few sums stay finite), and ordinary magnitudes carrying a
+/// full 15–17 significant digits. Use it to stress formatting, tick alignment, and PnL math
+/// that moderate price ranges never reach.
+pub fn extreme_price() -> impl Strategy<Value = f64> {
+    let tiny = (f64::MIN_POSITIVE.to_bits()..=1e-300_f64.to_bits()).prop_map(f64::from_bits);
+    let huge = (1e300_f64.to_bits()..=(f64::MAX / 1024.0).to_bits()).prop_map(f64::from_bits);
+    let precise = 1.0_f64..1_000_000.0;
+    prop_oneof![tiny, huge, precise]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coverage::assert_covers;
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    enum Edge {
+        On,
+        Above,
+        Below,
+    }
+
+    fn edge_of(price: f64, tick: f64) -> Edge {
+        let nearest = (price / tick).round() * tick;
+        match price.partial_cmp(&nearest) {
+            Some(std::cmp::Ordering::Greater) => Edge::Above,
+            Some(std::cmp::Ordering::Less) => Edge::Below,
+            _ => Edge::On,
+        }
+    }
+
+    #[test]
+    fn tick_edge_prices_hit_both_sides_of_the_boundary() {
+        // Values above/below are intentionally off-tick; that is the point of the generator.
+        assert_covers(
+            tick_edge_prices(0.01),
+            512,
+            &[Edge::On, Edge::Above, Edge::Below],
+            |p| edge_of(*p, 0.01),
+        );
+    }
+
+    #[test]
+    fn tick_edge_nudges_survive_large_and_tiny_prices() {
+        let large = 1e4 * 1_000_000.0;
+        assert!(
+            large + TICK_EDGE_EPSILON == large,
+            "plain epsilon is lost at this magnitude"
+        );
+        assert_eq!(tick_edge(large, 3), large + ulp(large));
+        assert!(tick_edge(large, 4) < large);
+
+        let tiny = 1e-13;
+        assert_eq!(tick_edge(tiny, 3), tiny + TICK_EDGE_EPSILON);
+        assert!(tick_edge(tiny, 4) > 0.0 && tick_edge(tiny, 4) < tiny);
+
+        let smallest = f64::from_bits(1);
+        assert_eq!(tick_edge(smallest, 2), smallest);
+        assert_eq!(tick_edge(smallest, 4), smallest);
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    enum Band {
+        Tiny,
+        Huge,
+        ManyDigits,
+        Other,
+    }
+
+    fn band_of(price: f64) -> Band {
+        let mantissa = format!("{price:e}");
+        let digits = mantissa
+            .split('e')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(char::is_ascii_digit)
+            .count();
+        if price < 1e-300 {
+            Band::Tiny
+        } else if price > 1e300 {
+            Band::Huge
+        } else if digits >= 15 {
+            Band::ManyDigits
+        } else {
+            Band::Other
+        }
+    }
+
+    #[test]
+    fn extreme_price_reaches_every_band() {
+        assert_covers(
+            extreme_price(),
+            256,
+            &[Band::Tiny, Band::Huge, Band::ManyDigits],
+            |p| band_of(*p),
+        );
+    }
 
     proptest! {
+        #[test]
+        fn extreme_price_is_positive_and_finite(v in extreme_price()) {
+            prop_assert!(v > 0.0 && v.is_finite(), "invalid price {v:e}");
+            prop_assert!(v.is_normal(), "subnormal price {v:e}");
+        }
+
         #[test]
         fn tick_alignment_holds(v in tick_aligned(1.0, 100.0, 0.25)) {
             let ticks = (v / 0.25).round();
             prop_assert!((v - ticks * 0.25).abs() < 1e-10);
         }
+
+        #[test]
+        fn tick_edges_land_on_their_side_of_the_tick(
+            ticks in 1..=1_000_000_i64,
+            edge in 0..5_u8,
+            tick_size in prop_oneof![Just(0.25), Just(1e-13), Just(1e4)],
+        ) {
+            let exact = ticks as f64 * tick_size;
+            let v = tick_edge(exact, edge);
+            prop_assert!(v > 0.0);
+            // The nudge itself plus rounding the nudged value to the nearest float.
+            prop_assert!((v - exact).abs() <= TICK_EDGE_EPSILON.max(ulp(exact)) + ulp(exact));
+            match edge {
+                1 | 3 => prop_assert!(v > exact, "edge {edge} should be above {exact:e}"),
+                2 | 4 => prop_assert!(v < exact, "edge {edge} should be below {exact:e}"),
+                _ => prop_assert_eq!(v, exact),
+            }
+        }
     }
 }
@@ -1,5 +1,6 @@
 //! Strategy helpers for boundary and edge-case values.
 
+use num_traits::PrimInt;
 use proptest::prelude::*;
 
 /// Include explicit `None` cases with normal values.
@@ -64,3 +65,92 @@ pub fn finite_f64_edge_values() -> impl Strategy<Value = f64> {
         2 => (-1_000_000.0_f64)..=1_000_000.0_f64,
     ]
 }
+
+/// Integers in `[pivot - radius, pivot + radius]`, weighted towards the pivot and its neighbors.
+///
+/// Useful for off-by-one checks around a specific threshold (e.g. a capacity limit). The range
+/// saturates at the type bounds instead of overflowing.
+pub fn around<T>(pivot: T, radius: T) -> impl Strategy<Value = T>
+where
+    T: PrimInt + std::fmt::Debug + 'static,
+{
+    assert!(radius >= T::zero(), "around: radius must be non-negative");
+    let lo = pivot.saturating_sub(radius);
+    let hi = pivot.saturating_add(radius);
+    let below = pivot
+        .checked_sub(&T::one())
+        .filter(|v| *v >= lo)
+        .unwrap_or(pivot);
+    let above = pivot
+        .checked_add(&T::one())
+        .filter(|v| *v <= hi)
+        .unwrap_or(pivot);
+
+    prop_oneof![
+        2 => Just(pivot),
+        1 => Just(below),
+        1 => Just(above),
+        1 => Just(lo),
+        1 => Just(hi),
+        4 => any::<u64>().prop_map(move |r| offset_within(lo, hi, r)),
+    ]
+}
+
+/// Map raw entropy `r` into `[lo, hi]`.
+fn offset_within<T: PrimInt>(lo: T, hi: T, r: u64) -> T {
+    match hi.checked_sub(&lo).and_then(|width| width.to_u64()) {
+        Some(width) if width < u64::MAX => {
+            lo + T::from(r % (width + 1)).expect("offset fits within the range width")
+        }
+        // Range wider than u64: spend one bit of entropy picking a half, then recurse.
+        _ => {
+            let mid = (lo >> 1) + (hi >> 1) + (lo & hi & T::one());
+            if r & 1 == 0 {
+                offset_within(lo, mid, r >> 1)
+            } else {
+                offset_within(mid + T::one(), hi, r >> 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coverage::assert_covers;
+
+    #[test]
+    fn around_produces_pivot_and_neighbors() {
+        assert_covers(around(100_u32, 5), 256, &[99, 100, 101, 95, 105], |v| *v);
+    }
+
+    #[test]
+    fn around_saturates_at_type_bounds() {
+        assert_covers(around(i64::MAX, 3), 128, &[i64::MAX - 1, i64::MAX], |v| *v);
+        assert_covers(around(0_u8, 2), 128, &[0, 1, 2], |v| *v);
+    }
+
+    #[test]
+    fn around_full_range_reaches_both_halves() {
+        // Far from the pivot and off the extremes, so only random offsets land in either bucket.
+        let far = |v: &i64| match *v {
+            v if v == -i64::MAX || v == i64::MAX => "edge",
+            v if v < -(1 << 62) => "lower",
+            v if v > 1 << 62 => "upper",
+            _ => "near",
+        };
+        assert_covers(around(0_i64, i64::MAX), 256, &["lower", "upper"], far);
+    }
+
+    proptest! {
+        #[test]
+        fn around_respects_bounds(v in around(1_000_i32, 10)) {
+            prop_assert!((990..=1_010).contains(&v));
+        }
+
+        #[test]
+        fn around_full_range_stays_in_type(v in around(0_i64, i64::MAX)) {
+            prop_assert!(v >= -i64::MAX);
+        }
+    }
+}
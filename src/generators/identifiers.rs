@@ -1,6 +1,8 @@
 //! Identifier/string strategy helpers.
 
 use proptest::prelude::*;
+use proptest::string::RegexGeneratorStrategy;
+use thiserror::Error;
 
 /// Generate an alphanumeric identifier of exact length.
 pub fn alphanumeric_id(len: usize) -> impl Strategy<Value = String> {
@@ -14,9 +16,104 @@ pub fn prefixed_id(prefix: &'static str, len: usize) -> impl Strategy<Value = St
     alphanumeric_id(len).prop_map(move |suffix| format!("{prefix}{suffix}"))
 }
 
+/// Error returned by [`regex_string`] for a pattern proptest cannot generate from.
+#[derive(Debug, Error)]
+#[error("invalid regex pattern {pattern:?}: {reason}")]
+pub struct InvalidPatternError {
+    /// The rejected pattern.
+    pub pattern: String,
+    /// Parser or generator explanation.
+    pub reason: String,
+}
+
+/// Generate strings matching `pattern`, e.g. an id format read from config.
+///
+/// The pattern is validated up front, so a bad config string yields an [`InvalidPatternError`]
+/// naming it instead of a panic deep inside proptest. Anchors are implicit: every sample matches
+/// the whole pattern. Note that `\d` and `\w` are Unicode classes; use `[0-9]` for ASCII digits.
+pub fn regex_string(pattern: &str) -> Result<RegexGeneratorStrategy<String>, InvalidPatternError> {
+    proptest::string::string_regex(pattern).map_err(|err| InvalidPatternError {
+        pattern: pattern.to_string(),
+        reason: err.to_string(),
+    })
+}
+
+/// Characters used by [`confusable_id`]: homoglyphs of ASCII letters, zero-width characters and
+/// bidirectional controls.
+pub const CONFUSABLE_CHARS: &[char] = &[
+    '\u{0430}', // CYRILLIC SMALL LETTER A
+    '\u{0435}', // CYRILLIC SMALL LETTER IE
+    '\u{043E}', // CYRILLIC SMALL LETTER O
+    '\u{0440}', // CYRILLIC SMALL LETTER ER
+    '\u{0441}', // CYRILLIC SMALL LETTER ES
+    '\u{03BF}', // GREEK SMALL LETTER OMICRON
+    '\u{0391}', // GREEK CAPITAL LETTER ALPHA
+    '\u{0131}', // LATIN SMALL LETTER DOTLESS I
+    '\u{FF21}', // FULLWIDTH LATIN CAPITAL LETTER A
+    '\u{200B}', // ZERO WIDTH SPACE
+    '\u{200C}', // ZERO WIDTH NON-JOINER
+    '\u{200D}', // ZERO WIDTH JOINER
+    '\u{202D}', // LEFT-TO-RIGHT OVERRIDE
+    '\u{202E}', // RIGHT-TO-LEFT OVERRIDE
+    '\u{2067}', // RIGHT-TO-LEFT ISOLATE
+    '\u{2069}', // POP DIRECTIONAL ISOLATE
+];
+
+/// Generate an alphanumeric identifier with 1-3 [`CONFUSABLE_CHARS`] spliced in.
+///
+/// Intended for security tests of code that compares, normalizes or displays identifiers
+/// (homograph and bidi-spoofing attacks).
+pub fn confusable_id() -> impl Strategy<Value = String> {
+    (
+        proptest::string::string_regex("[A-Za-z0-9]{4,12}")
+            .expect("alphanumeric regex should compile"),
+        prop::collection::vec(
+            (
+                prop::sample::select(CONFUSABLE_CHARS),
+                any::<prop::sample::Index>(),
+            ),
+            1..=3,
+        ),
+    )
+        .prop_map(|(base, insertions)| {
+            let mut chars: Vec<char> = base.chars().collect();
+            for (ch, position) in insertions {
+                let at = position.index(chars.len() + 1);
+                chars.insert(at, ch);
+            }
+            chars.into_iter().collect()
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::seed::{draw_values, seeded_runner};
+
+    #[test]
+    fn regex_string_samples_match_pattern() {
+        let strategy = regex_string(r"[A-Z]{3}-\d{4}").expect("pattern should compile");
+        for sample in draw_values(&strategy, &mut seeded_runner(11), 128) {
+            let chars: Vec<char> = sample.chars().collect();
+            assert_eq!(chars.len(), 8, "unexpected sample {sample:?}");
+            assert!(
+                chars[..3].iter().all(char::is_ascii_uppercase),
+                "{sample:?}"
+            );
+            assert_eq!(chars[3], '-', "{sample:?}");
+            assert!(chars[4..].iter().all(|c| c.is_numeric()), "{sample:?}");
+        }
+    }
+
+    #[test]
+    fn regex_string_rejects_invalid_pattern() {
+        let err = regex_string("[A-Z{3}").expect_err("unclosed class should be rejected");
+        assert_eq!(err.pattern, "[A-Z{3}");
+        assert!(
+            err.to_string()
+                .starts_with("invalid regex pattern \"[A-Z{3}\"")
+        );
+    }
 
     proptest! {
         #[test]
@@ -24,5 +121,12 @@ mod tests {
             prop_assert!(id.starts_with("ord_"));
             prop_assert_eq!(id.len(), 10);
         }
+
+        #[test]
+        fn confusable_id_contains_confusable(id in confusable_id()) {
+            prop_assert!(!id.is_ascii());
+            prop_assert!(id.chars().any(|c| CONFUSABLE_CHARS.contains(&c)));
+            prop_assert!(std::str::from_utf8(id.as_bytes()).is_ok());
+        }
     }
 }
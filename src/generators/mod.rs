@@ -4,5 +4,9 @@ pub mod collections;
 pub mod decimals;
 pub mod edge_values;
 pub mod identifiers;
+#[cfg(feature = "mutation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mutation")))]
+pub mod mutation;
 pub mod numeric;
 pub mod temporal;
+pub mod text;
@@ -0,0 +1,188 @@
+//! Strategies for mutation orchestration types.
+
+use proptest::prelude::*;
+
+use crate::generators::edge_values::with_none;
+use crate::generators::identifiers::alphanumeric_id;
+use crate::mutation::events::{MutantSpec, MutationEvent, MutationOutcome, MutationType};
+use crate::mutation::{ArtifactPolicy, MutationConfig, MutationTypeRules};
+
+/// Generate any [`ArtifactPolicy`] variant.
+pub fn artifact_policy_strategy() -> impl Strategy<Value = ArtifactPolicy> {
+    prop_oneof![
+        Just(ArtifactPolicy::All),
+        Just(ArtifactPolicy::FailuresOnly),
+        Just(ArtifactPolicy::SurvivorsAndErrors),
+        Just(ArtifactPolicy::None),
+    ]
+}
+
+/// Generate valid [`MutationConfig`] values with varied filters, timeouts, policies, packages,
+/// pass-through arguments, and report options.
+pub fn mutation_config_strategy() -> impl Strategy<Value = MutationConfig> {
+    let flag = || alphanumeric_id(6).prop_map(|name| format!("--{name}"));
+    (
+        alphanumeric_id(8),
+        with_none(alphanumeric_id(4)),
+        with_none(1_u64..=3_600),
+        artifact_policy_strategy(),
+        prop::collection::vec(alphanumeric_id(6), 0..3),
+        prop::collection::vec(flag(), 0..3),
+        prop::collection::vec(flag(), 0..3),
+        any::<bool>(),
+        with_none(alphanumeric_id(6).prop_map(|repo| format!("https://github.com/o/{repo}/blob"))),
+    )
+        .prop_map(
+            |(
+                project,
+                filter,
+                timeout_secs,
+                artifact_policy,
+                packages,
+                extra_args,
+                test_args,
+                reuse_clean_runs,
+                source_base_url,
+            )| {
+                let project_dir = std::env::temp_dir().join(project);
+                let run_root = project_dir
+                    .join(".kitchensink-testing")
+                    .join("mutation")
+                    .join("runs");
+                MutationConfig {
+                    project_dir,
+                    run_root,
+                    filter,
+                    timeout_secs,
+                    artifact_policy,
+                    packages,
+                    extra_args,
+                    test_args,
+                    reuse_clean_runs,
+                    source_base_url,
+                    type_rules: MutationTypeRules::default(),
+                }
+            },
+        )
+}
+
+/// Generate any [`MutationOutcome`], with short error messages.
+pub fn mutation_outcome_strategy() -> impl Strategy<Value = MutationOutcome> {
+    prop_oneof![
+        Just(MutationOutcome::Killed),
+        Just(MutationOutcome::Survived),
+        Just(MutationOutcome::Timeout),
+        Just(MutationOutcome::Unviable),
+        Just(MutationOutcome::Skipped),
+        alphanumeric_id(6).prop_map(|message| MutationOutcome::Error { message }),
+    ]
+}
+
+/// Generate a well-formed synthetic `events.jsonl` sequence for one run.
+///
+/// The log starts with `RunStarted`, discovers up to `max_mutants` mutants, and takes each one
+/// to a random stage (pending, running, or finished with any outcome), optionally ending with
+/// an interruption or completion marker. Timestamps strictly increase.
+pub fn mutation_event_log_strategy(
+    max_mutants: usize,
+) -> impl Strategy<Value = Vec<MutationEvent>> {
+    let stage = (0_u8..=2, mutation_outcome_strategy());
+    (
+        prop::collection::vec(stage, 0..=max_mutants),
+        0_u8..=2,
+        1_600_000_000_000_i64..1_900_000_000_000,
+    )
+        .prop_map(|(stages, ending, start_ms)| {
+            let run_id = "run-synthetic".to_string();
+            let mut clock = start_ms;
+            let mut tick = || {
+                clock += 1;
+                clock
+            };
+            let mut events = vec![MutationEvent::RunStarted {
+                run_id: run_id.clone(),
+                timestamp_ms: tick(),
+                discovered: stages.len(),
+                discovery_streaming: false,
+                config: None,
+                metadata: None,
+            }];
+            let ids: Vec<String> = (0..stages.len()).map(|i| format!("m{i}")).collect();
+            for id in &ids {
+                events.push(MutationEvent::MutantDiscovered {
+                    run_id: run_id.clone(),
+                    timestamp_ms: tick(),
+                    mutant: MutantSpec {
+                        id: id.clone(),
+                        label: format!("src/lib.rs:1:1: mutant {id}"),
+                        selector: id.clone(),
+                        source_file: "src/lib.rs".to_string(),
+                        source_line: 1,
+                        function: None,
+                        mutation_type: MutationType::Unknown,
+                        original_code: String::new(),
+                        mutated_code: String::new(),
+                    },
+                });
+            }
+            for (id, (stage, outcome)) in ids.iter().zip(stages) {
+                if stage == 0 {
+                    continue;
+                }
+                let started_at_ms = tick();
+                events.push(MutationEvent::MutantStarted {
+                    run_id: run_id.clone(),
+                    timestamp_ms: started_at_ms,
+                    mutant_id: id.clone(),
+                });
+                if stage == 2 {
+                    let finished_at_ms = tick();
+                    events.push(MutationEvent::MutantFinished {
+                        run_id: run_id.clone(),
+                        timestamp_ms: finished_at_ms,
+                        mutant_id: id.clone(),
+                        outcome,
+                        exit_code: None,
+                        stdout_artifact_path: None,
+                        stderr_artifact_path: None,
+                        started_at_ms: Some(started_at_ms),
+                        finished_at_ms: Some(finished_at_ms),
+                        duration_ms: None,
+                        tests_run: Vec::new(),
+                        tests_failed: Vec::new(),
+                        stdout_preview: None,
+                        stderr_preview: None,
+                    });
+                }
+            }
+            match ending {
+                1 => events.push(MutationEvent::RunInterrupted {
+                    run_id: run_id.clone(),
+                    timestamp_ms: tick(),
+                    reason: "synthetic interruption".to_string(),
+                }),
+                2 => events.push(MutationEvent::RunCompleted {
+                    run_id,
+                    timestamp_ms: tick(),
+                    discovered: None,
+                }),
+                _ => {}
+            }
+            events
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn generated_configs_are_valid(config in mutation_config_strategy()) {
+            prop_assert!(config.run_root.starts_with(&config.project_dir));
+            prop_assert!(config.timeout_secs.is_none_or(|secs| secs > 0));
+            prop_assert!(config.filter.as_ref().is_none_or(|filter| !filter.is_empty()));
+            prop_assert!(config.extra_args.iter().chain(&config.test_args).all(|arg| arg.starts_with("--")));
+        }
+    }
+}
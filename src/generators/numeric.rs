@@ -27,6 +27,48 @@ pub fn positive_f64(min: f64, max: f64) -> impl Strategy<Value = f64> {
     min..=max
 }
 
+/// Generate strictly increasing `f64` vectors of length `n` with steps in `[min_step, max_step]`.
+///
+/// The continuous counterpart of `monotonic_timestamps`. Sequences start in `[-1e6, 1e6]`; when
+/// a step is too small to be representable at the current magnitude the next representable
+/// value is used instead, so strict monotonicity always holds.
+pub fn monotonic_f64s(n: usize, min_step: f64, max_step: f64) -> impl Strategy<Value = Vec<f64>> {
+    assert!(n > 0, "n must be > 0");
+    assert!(min_step > 0.0, "min_step must be positive");
+    assert!(max_step >= min_step, "max_step must be >= min_step");
+    assert!(
+        max_step * (n as f64) <= f64::MAX / 2.0,
+        "n * max_step would overflow to infinity"
+    );
+
+    (
+        -1_000_000.0_f64..=1_000_000.0,
+        prop::collection::vec(min_step..=max_step, n - 1),
+    )
+        .prop_map(|(start, steps)| {
+            let mut out = Vec::with_capacity(steps.len() + 1);
+            out.push(start);
+            let mut prev = start;
+            for step in steps {
+                let next = prev + step;
+                prev = if next > prev { next } else { next_up(prev) };
+                out.push(prev);
+            }
+            out
+        })
+}
+
+/// Smallest representable `f64` strictly greater than finite `value`.
+fn next_up(value: f64) -> f64 {
+    if value == 0.0 {
+        f64::from_bits(1)
+    } else if value > 0.0 {
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,12 +76,19 @@ mod tests {
     proptest! {
         #[test]
         fn bounded_respects_range(v in bounded_f64(-2.0, 3.0)) {
-            prop_assert!(v >= -2.0 && v <= 3.0);
+            prop_assert!((-2.0..=3.0).contains(&v));
         }
 
         #[test]
         fn finite_is_finite(v in finite_f64()) {
             prop_assert!(v.is_finite());
         }
+
+        #[test]
+        fn monotonic_f64s_strictly_increase(values in monotonic_f64s(50, 1e-12, 1e300)) {
+            prop_assert_eq!(values.len(), 50);
+            prop_assert!(values.iter().all(|v| v.is_finite()));
+            prop_assert!(values.windows(2).all(|w| w[1] > w[0]));
+        }
     }
 }
@@ -4,6 +4,7 @@ use proptest::prelude::*;
 
 const DEFAULT_START_MS: i64 = 1_577_836_800_000; // 2020-01-01T00:00:00Z
 const DEFAULT_END_MS: i64 = 2_524_607_999_000; // 2049-12-31T23:59:59Z
+const SKEW_MAX_FORWARD_MS: i64 = 1_000;
 
 /// Generate unix timestamp milliseconds in a practical default range.
 pub fn valid_timestamp_millis() -> impl Strategy<Value = i64> {
@@ -48,9 +49,70 @@ pub fn monotonic_timestamps(
     })
 }
 
+/// Generate `count` timestamps in `[min_ms, max_ms]` that are never strictly increasing.
+///
+/// The negative counterpart of [`monotonic_timestamps`], for testing that ingestion code rejects
+/// or re-sorts out-of-order data. A shuffle that happens to come out sorted is reversed.
+pub fn shuffled_timestamps(
+    count: usize,
+    min_ms: i64,
+    max_ms: i64,
+) -> impl Strategy<Value = Vec<i64>> {
+    assert!(count >= 2, "count must be >= 2 to be out of order");
+    assert!(max_ms > min_ms, "max_ms must be > min_ms");
+
+    prop::collection::vec(min_ms..=max_ms, count)
+        .prop_shuffle()
+        .prop_map(|mut ts| {
+            if ts.windows(2).all(|w| w[1] > w[0]) {
+                ts.reverse();
+            }
+            ts
+        })
+}
+
+/// Generate `count` timestamps that mostly advance but sometimes jump backward.
+///
+/// Simulates wall clocks corrected by NTP: each step moves forward by up to one second, or (one
+/// step in ten) back by up to `max_backward_ms`. Every sequence contains at least one backward
+/// jump, so code that must tolerate non-monotonic clocks is always exercised.
+pub fn clock_skew_timestamps(
+    count: usize,
+    max_backward_ms: i64,
+) -> impl Strategy<Value = Vec<i64>> {
+    assert!(count >= 2, "count must be >= 2 to jump backward");
+    assert!(max_backward_ms > 0, "max_backward_ms must be > 0");
+
+    let step = prop_oneof![
+        9 => 1..=SKEW_MAX_FORWARD_MS,
+        1 => (1..=max_backward_ms).prop_map(|back| -back),
+    ];
+    (
+        valid_timestamp_millis(),
+        prop::collection::vec(step, count - 1),
+        any::<prop::sample::Index>(),
+        1..=max_backward_ms,
+    )
+        .prop_map(|(start, mut steps, forced, back)| {
+            if steps.iter().all(|step| *step > 0) {
+                let at = forced.index(steps.len());
+                steps[at] = -back;
+            }
+            let mut out = Vec::with_capacity(steps.len() + 1);
+            out.push(start);
+            for step in steps {
+                let next = out.last().copied().unwrap_or(start).saturating_add(step);
+                out.push(next);
+            }
+            out
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::law::invariants::assert_monotonic_increasing;
+    use crate::law::panics::assert_panics_with;
 
     proptest! {
         #[test]
@@ -59,5 +121,27 @@ mod tests {
                 prop_assert!(w[1] > w[0]);
             }
         }
+
+        #[test]
+        fn shuffled_generation_is_out_of_order(ts in shuffled_timestamps(6, 1_000, 2_000)) {
+            prop_assert_eq!(ts.len(), 6);
+            prop_assert!(ts.iter().all(|t| (1_000..=2_000).contains(t)));
+            prop_assert!(!ts.windows(2).all(|w| w[1] > w[0]));
+            assert_panics_with("sequence is not strictly increasing", || {
+                assert_monotonic_increasing(&ts)
+            });
+        }
+
+        #[test]
+        fn clock_skew_jumps_back_within_bound(ts in clock_skew_timestamps(12, 250)) {
+            prop_assert_eq!(ts.len(), 12);
+            let backward: Vec<i64> = ts
+                .windows(2)
+                .filter(|w| w[1] < w[0])
+                .map(|w| w[0] - w[1])
+                .collect();
+            prop_assert!(!backward.is_empty(), "no backward jump in {:?}", ts);
+            prop_assert!(backward.iter().all(|jump| *jump <= 250), "jump too large in {:?}", ts);
+        }
     }
 }
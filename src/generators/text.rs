@@ -0,0 +1,97 @@
+//! Text generators for parser and matcher tests.
+
+use proptest::prelude::*;
+
+const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+const BRACKETS: [char; 6] = ['(', ')', '[', ']', '{', '}'];
+
+/// Generate strings of correctly nested `()`, `[]` and `{}`, at most `max_depth` levels deep.
+///
+/// The empty string is included; `max_depth == 0` only produces it.
+pub fn balanced_brackets(max_depth: u32) -> impl Strategy<Value = String> {
+    Just(String::new()).prop_recursive(max_depth, 64, 4, |inner| {
+        prop::collection::vec((0..PAIRS.len(), inner), 1..4).prop_map(|groups| {
+            groups
+                .into_iter()
+                .map(|(pair, body)| {
+                    let (open, close) = PAIRS[pair];
+                    format!("{open}{body}{close}")
+                })
+                .collect()
+        })
+    })
+}
+
+/// Generate bracket strings that are **not** balanced, for negative parser tests.
+///
+/// Each value is a balanced string with one corruption: a stray bracket inserted, a bracket
+/// removed, or a closer swapped for a different kind.
+pub fn unbalanced_brackets() -> impl Strategy<Value = String> {
+    (
+        balanced_brackets(4),
+        0..3_u8,
+        any::<prop::sample::Index>(),
+        0..BRACKETS.len(),
+    )
+        .prop_map(|(balanced, corruption, position, bracket)| {
+            let mut chars: Vec<char> = balanced.chars().collect();
+            let closers: Vec<usize> = (0..chars.len())
+                .filter(|&i| PAIRS.iter().any(|&(_, close)| close == chars[i]))
+                .collect();
+            match corruption {
+                1 if !chars.is_empty() => {
+                    chars.remove(position.index(chars.len()));
+                }
+                2 if !closers.is_empty() => {
+                    let at = closers[position.index(closers.len())];
+                    let kind = PAIRS.iter().position(|&(_, c)| c == chars[at]).unwrap_or(0);
+                    chars[at] = PAIRS[(kind + 1 + bracket % 2) % PAIRS.len()].1;
+                }
+                _ => chars.insert(position.index(chars.len() + 1), BRACKETS[bracket]),
+            }
+            chars.into_iter().collect()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_balanced(text: &str) -> bool {
+        let mut stack = Vec::new();
+        for c in text.chars() {
+            if let Some(&(_, close)) = PAIRS.iter().find(|&&(open, _)| open == c) {
+                stack.push(close);
+            } else if stack.pop() != Some(c) {
+                return false;
+            }
+        }
+        stack.is_empty()
+    }
+
+    fn depth(text: &str) -> u32 {
+        let (mut current, mut max) = (0_u32, 0_u32);
+        for c in text.chars() {
+            if PAIRS.iter().any(|&(open, _)| open == c) {
+                current += 1;
+                max = max.max(current);
+            } else {
+                current -= 1;
+            }
+        }
+        max
+    }
+
+    proptest! {
+        #[test]
+        fn balanced_brackets_pass_reference_checker(text in balanced_brackets(5)) {
+            prop_assert!(is_balanced(&text), "not balanced: {text:?}");
+            prop_assert!(depth(&text) <= 5, "too deep: {text:?}");
+        }
+
+        #[test]
+        fn unbalanced_brackets_fail_reference_checker(text in unbalanced_brackets()) {
+            prop_assert!(!is_balanced(&text), "unexpectedly balanced: {text:?}");
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Snapshot-based golden file assertions for rendered output.
+
+use std::path::Path;
+
+/// Environment variable that switches golden assertions into regeneration mode.
+pub const UPDATE_GOLDEN_ENV: &str = "KITCHENSINK_UPDATE_GOLDEN";
+
+/// True when [`UPDATE_GOLDEN_ENV`] is set to a non-empty value other than `0`/`false`.
+pub fn update_goldens_from_env() -> bool {
+    std::env::var(UPDATE_GOLDEN_ENV)
+        .map(|value| {
+            let value = value.trim();
+            !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+        })
+        .unwrap_or(false)
+}
+
+/// Assert `actual` matches the contents of the golden file at `golden_path`.
+///
+/// With `update == true` the golden file (and its parent directories) is rewritten with `actual`
+/// instead of compared. Panics when the golden file is missing or differs, reporting the first
+/// differing line.
+pub fn assert_matches_golden(actual: &str, golden_path: &Path, update: bool) {
+    if update {
+        if let Some(parent) = golden_path.parent() {
+            std::fs::create_dir_all(parent).expect("golden parent directory should be created");
+        }
+        std::fs::write(golden_path, actual).expect("golden file should be written");
+        return;
+    }
+
+    let expected = match std::fs::read_to_string(golden_path) {
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "golden file {} could not be read ({err}); set {UPDATE_GOLDEN_ENV}=1 to create it",
+            golden_path.display()
+        ),
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1usize;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (e, a) => panic!(
+                "output drifted from golden file {} at line {line}:\n  expected: {:?}\n    actual: {:?}\nset {UPDATE_GOLDEN_ENV}=1 to regenerate",
+                golden_path.display(),
+                e.unwrap_or("<end of file>"),
+                a.unwrap_or("<end of output>"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::law::panics::assert_panics_with;
+
+    #[test]
+    fn golden_is_created_then_matched() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let golden = tmp.path().join("goldens").join("report.md");
+        let rendered = "# Mutation Run run-1\n\n| killed | 1 |\n";
+
+        assert_matches_golden(rendered, &golden, true);
+        assert_eq!(
+            std::fs::read_to_string(&golden).expect("golden should be readable"),
+            rendered
+        );
+        assert_matches_golden(rendered, &golden, false);
+    }
+
+    #[test]
+    fn golden_drift_is_detected() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let golden = tmp.path().join("report.md");
+        assert_matches_golden("| killed | 1 |\n| survived | 0 |\n", &golden, true);
+
+        assert_panics_with("at line 2", || {
+            assert_matches_golden("| killed | 1 |\n| survived | 2 |\n", &golden, false);
+        });
+        assert_panics_with("| survived | 2 |", || {
+            assert_matches_golden("| killed | 1 |\n| survived | 2 |\n", &golden, false);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be read")]
+    fn missing_golden_panics_with_update_hint() {
+        let tmp = tempdir().expect("tempdir should be created");
+        assert_matches_golden("anything", &tmp.path().join("missing.md"), false);
+    }
+}
@@ -1,6 +1,8 @@
 //! Algebraic law assertions.
 
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
 
 /// Assert commutativity: `f(a, b) == f(b, a)`.
 pub fn assert_commutative<T, F, R>(a: T, b: T, f: F)
@@ -14,6 +16,52 @@ where
     assert_eq!(left, right, "operation should be commutative");
 }
 
+/// Assert commutativity for every pair drawn from `values`.
+///
+/// Checks each unordered pair (including `(a, a)`) once and panics with the first offending
+/// `(a, b)` and both results.
+pub fn assert_commutative_all<T, F, R>(values: &[T], f: F)
+where
+    T: Clone + Debug,
+    F: Fn(T, T) -> R,
+    R: PartialEq + Debug,
+{
+    for (i, a) in values.iter().enumerate() {
+        for b in &values[i..] {
+            let left = f(a.clone(), b.clone());
+            let right = f(b.clone(), a.clone());
+            assert!(
+                left == right,
+                "operation should be commutative for ({:?}, {:?}): {:?} != {:?}",
+                a,
+                b,
+                left,
+                right
+            );
+        }
+    }
+}
+
+/// Assert floating-point commutativity within an absolute tolerance.
+///
+/// Exact [`assert_commutative`] compares with `PartialEq`, but an operation built from several
+/// float steps (e.g. `(a + k) + b`) rounds differently once its operands swap, so the two
+/// results can differ in the last bits while being mathematically equal. Panics reporting the
+/// gap between `f(a, b)` and `f(b, a)`.
+pub fn assert_commutative_approx<F>(a: f64, b: f64, f: F, tolerance: f64)
+where
+    F: Fn(f64, f64) -> f64,
+{
+    assert!(tolerance >= 0.0, "tolerance must be non-negative");
+    let left = f(a, b);
+    let right = f(b, a);
+    let gap = (left - right).abs();
+    assert!(
+        gap <= tolerance,
+        "operation should be approximately commutative: f({a:?}, {b:?}) = {left:?}, f({b:?}, {a:?}) = {right:?}, gap {gap:e} exceeds {tolerance:e}"
+    );
+}
+
 /// Assert associativity: `f(f(a,b),c) == f(a,f(b,c))`.
 pub fn assert_associative<T, F>(a: T, b: T, c: T, f: F)
 where
@@ -48,3 +96,143 @@ where
     let right = g(f(a.clone(), b), f(a, c));
     assert_eq!(left, right, "left distributivity should hold");
 }
+
+/// Assert floating-point associativity within an absolute tolerance.
+///
+/// Float addition and multiplication round after every operation, so regrouping changes the
+/// accumulated error and exact [`assert_associative`] is unrealistic for float pipelines.
+/// Passing means regrouping stays within `tolerance`, a numerical-stability property of the
+/// reduction rather than an algebraic law. Panics reporting the rounding gap between the two
+/// groupings.
+pub fn assert_associative_approx<F>(a: f64, b: f64, c: f64, f: F, tolerance: f64)
+where
+    F: Fn(f64, f64) -> f64,
+{
+    assert!(tolerance >= 0.0, "tolerance must be non-negative");
+    let left = f(f(a, b), c);
+    let right = f(a, f(b, c));
+    let gap = (left - right).abs();
+    assert!(
+        gap <= tolerance,
+        "operation should be approximately associative: f(f({a:?}, {b:?}), {c:?}) = {left:?}, f({a:?}, f({b:?}, {c:?})) = {right:?}, rounding gap {gap:e} exceeds {tolerance:e}"
+    );
+}
+
+/// Assert `g` inverts `f` at `x`: `g(f(x)) == x`.
+///
+/// The two-type counterpart of `assert_involutive`, for encode/decode and to/from-bytes pairs.
+pub fn assert_inverse<T, U, F, G>(x: T, f: F, g: G)
+where
+    T: Clone + PartialEq + Debug,
+    F: Fn(T) -> U,
+    G: Fn(U) -> T,
+{
+    let restored = g(f(x.clone()));
+    assert_eq!(x, restored, "g should invert f");
+}
+
+/// Assert `op` is closed over `elements`: `op(a, b)` is a member for every ordered pair.
+///
+/// Closure is the precondition for calling a structure a magma (and hence a monoid or group).
+/// Panics with the first `(a, b)` whose result escapes the set.
+pub fn assert_closed<T, F>(elements: &[T], op: F)
+where
+    T: Eq + Hash + Clone + Debug,
+    F: Fn(T, T) -> T,
+{
+    let members: HashSet<&T> = elements.iter().collect();
+    for a in elements {
+        for b in elements {
+            let result = op(a.clone(), b.clone());
+            assert!(
+                members.contains(&result),
+                "operation should be closed over {:?}: ({:?}, {:?}) produced {:?}",
+                elements,
+                a,
+                b,
+                result
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::law::panics::assert_panics_with;
+
+    #[test]
+    fn float_addition_is_only_approximately_associative() {
+        assert_panics_with("operation should be associative", || {
+            assert_associative(0.1_f64, 0.2, 0.3, |x, y| x + y);
+        });
+        assert_associative_approx(0.1, 0.2, 0.3, |x, y| x + y, 1e-15);
+        assert_associative_approx(1e-3, 7.0, 1e5, |x, y| x * y, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "rounding gap 1.1102230246251565e-16 exceeds 0e0")]
+    fn gap_beyond_tolerance_is_reported() {
+        assert_associative_approx(0.1, 0.2, 0.3, |x, y| x + y, 0.0);
+    }
+
+    #[test]
+    fn offset_sum_is_only_approximately_commutative() {
+        let offset_sum = |x: f64, y: f64| (x + 0.3) + y;
+        assert_panics_with("operation should be commutative", || {
+            assert_commutative(0.1_f64, 0.2, offset_sum);
+        });
+        assert_commutative_approx(0.1, 0.2, offset_sum, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation should be approximately commutative: f(1.0, 2.0) = -1.0")]
+    fn non_commutative_float_op_exceeds_tolerance() {
+        assert_commutative_approx(1.0, 2.0, |x, y| x - y, 1e-9);
+    }
+
+    proptest! {
+        #[test]
+        fn integer_format_then_parse_is_identity(x in any::<i32>()) {
+            assert_inverse(
+                x,
+                |n| n.to_string(),
+                |s| s.parse::<i32>().expect("formatted integer should parse"),
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "g should invert f")]
+    fn lossy_inverse_is_reported() {
+        assert_inverse(
+            -7_i32,
+            |n| n.unsigned_abs().to_string(),
+            |s| s.parse().unwrap(),
+        );
+    }
+
+    #[test]
+    fn integer_max_commutes_for_all_pairs() {
+        assert_commutative_all(&[i64::MIN, -3, 0, 7, i64::MAX], |a, b| a.max(b));
+    }
+
+    #[test]
+    #[should_panic(expected = "operation should be commutative for (1, 2): -1 != 1")]
+    fn non_commutative_op_reports_pair() {
+        assert_commutative_all(&[1_i32, 2, 3], |a, b| a - b);
+    }
+
+    #[test]
+    fn xor_is_closed_over_bits() {
+        assert_closed(&[0_u8, 1], |a, b| a ^ b);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation should be closed over [0, 1]: (1, 1) produced 2")]
+    fn addition_escapes_bits() {
+        assert_closed(&[0_u8, 1], |a, b| a + b);
+    }
+}
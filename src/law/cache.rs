@@ -0,0 +1,68 @@
+//! Cache and memoization consistency assertions.
+
+use std::fmt::Debug;
+
+/// Assert a cache agrees with its backing function for every key.
+///
+/// `cache` is called as a lookup (it may populate itself on a miss, hence `FnMut`) and compared
+/// against a fresh `backing(k)`. Keys are visited in order, so repeating a key after mutating the
+/// backing source catches stale entries. Panics with the first inconsistent key.
+pub fn assert_cache_consistent<K, V, Cache, F>(keys: &[K], mut cache: Cache, backing: F)
+where
+    K: Clone + Debug,
+    V: PartialEq + Debug,
+    Cache: FnMut(&K) -> V,
+    F: Fn(&K) -> V,
+{
+    for (index, key) in keys.iter().enumerate() {
+        let cached = cache(key);
+        let expected = backing(key);
+        assert!(
+            cached == expected,
+            "cache inconsistent for key {:?} at index {}: cached {:?}, backing {:?}",
+            key,
+            index,
+            cached,
+            expected
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn memoizer_matches_backing_function() {
+        let mut memo: HashMap<u64, u64> = HashMap::new();
+        let square = |k: &u64| k * k;
+        assert_cache_consistent(
+            &[3, 4, 3, 0, 4],
+            |k| *memo.entry(*k).or_insert_with(|| square(k)),
+            square,
+        );
+        assert_eq!(memo.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cache inconsistent for key \"btc\" at index 1: cached 100, backing 101"
+    )]
+    fn stale_entry_is_reported() {
+        let price = Cell::new(100);
+        let mut memo: HashMap<&str, i32> = HashMap::new();
+        assert_cache_consistent(
+            &["btc", "btc"],
+            |k| *memo.entry(*k).or_insert_with(|| price.get()),
+            |_| {
+                // The source moves after every read but the memo never invalidates.
+                let current = price.get();
+                price.set(current + 1);
+                current
+            },
+        );
+    }
+}
@@ -0,0 +1,707 @@
+//! Collection transformation law assertions.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Assert a transformation preserves the multiset of keys between `input` and `output`.
+///
+/// Stronger than comparing lengths: every input key must appear in the output exactly as many
+/// times as in the input (e.g. each order id survives a re-pricing pass once). Panics with the
+/// first dropped key (in input order) or, failing that, the first added key (in output order).
+pub fn assert_key_preserved<T, U, K, Fin, Fout>(
+    input: &[T],
+    output: &[U],
+    in_key: Fin,
+    out_key: Fout,
+) where
+    K: Eq + Hash + Debug,
+    Fin: Fn(&T) -> K,
+    Fout: Fn(&U) -> K,
+{
+    let mut balance: HashMap<K, i64> = HashMap::new();
+    for item in input {
+        *balance.entry(in_key(item)).or_default() += 1;
+    }
+    for item in output {
+        *balance.entry(out_key(item)).or_default() -= 1;
+    }
+
+    for item in input {
+        let key = in_key(item);
+        let count = balance[&key];
+        assert!(
+            count <= 0,
+            "key {:?} dropped by transformation ({} fewer occurrence(s) in output)",
+            key,
+            count
+        );
+    }
+    for item in output {
+        let key = out_key(item);
+        let count = balance[&key];
+        assert!(
+            count >= 0,
+            "key {:?} added by transformation ({} extra occurrence(s) in output)",
+            key,
+            -count
+        );
+    }
+}
+
+/// Assert `values` is sorted according to `compare`, e.g. candles by timestamp.
+///
+/// Adjacent equal elements are allowed. Panics with the first out-of-order adjacent pair and
+/// their indices.
+pub fn assert_sorted_by<T, F>(values: &[T], compare: F)
+where
+    T: Debug,
+    F: Fn(&T, &T) -> Ordering,
+{
+    for (offset, window) in values.windows(2).enumerate() {
+        assert!(
+            compare(&window[0], &window[1]) != Ordering::Greater,
+            "not sorted: element {} {:?} should not precede element {} {:?}",
+            offset,
+            window[0],
+            offset + 1,
+            window[1]
+        );
+    }
+}
+
+/// Assert `values` is in ascending order of `key(value)`; equal keys are allowed.
+///
+/// The ergonomic form of [`assert_sorted_by`] for domain structs. Panics with the index of the
+/// first element whose key is smaller than its predecessor's, and both keys.
+pub fn assert_sorted_by_key<T, K, F>(values: &[T], key: F)
+where
+    K: Ord + Debug,
+    F: Fn(&T) -> K,
+{
+    let mut previous: Option<K> = None;
+    for (index, value) in values.iter().enumerate() {
+        let current = key(value);
+        if let Some(previous) = &previous {
+            assert!(
+                current >= *previous,
+                "not sorted by key at index {}: key {:?} follows key {:?}",
+                index,
+                current,
+                previous
+            );
+        }
+        previous = Some(current);
+    }
+}
+
+/// Assert every element satisfying `predicate` precedes every element that does not.
+///
+/// The invariant of `partition`-style passes (e.g. fillable orders before resting ones).
+/// Panics with the boundary index where the first failing element sits and the later element
+/// that satisfies the predicate anyway.
+pub fn assert_partitioned<T, F>(values: &[T], predicate: F)
+where
+    T: Debug,
+    F: Fn(&T) -> bool,
+{
+    let Some(boundary) = values.iter().position(|value| !predicate(value)) else {
+        return;
+    };
+    if let Some(offset) = values[boundary..].iter().position(&predicate) {
+        let index = boundary + offset;
+        panic!(
+            "not partitioned: boundary at index {} ({:?} fails the predicate) but index {} ({:?}) satisfies it",
+            boundary, values[boundary], index, values[index]
+        );
+    }
+}
+
+/// Assert no two consecutive elements are equal, as after `dedup` or run-length encoding.
+///
+/// Weaker than global uniqueness: values may repeat as long as they are not adjacent. Panics
+/// with the index of the first element equal to its predecessor.
+pub fn assert_no_adjacent_duplicates<T>(values: &[T])
+where
+    T: PartialEq + Debug,
+{
+    for (offset, window) in values.windows(2).enumerate() {
+        assert!(
+            window[0] != window[1],
+            "adjacent duplicate at index {}: {:?} repeats index {}",
+            offset + 1,
+            window[1],
+            offset
+        );
+    }
+}
+
+/// Assert `chunks` concatenate back to `original` with nothing lost, duplicated, or reordered.
+///
+/// For windowing and splitting functions. Panics with the first divergent index, or with both
+/// lengths when one side is a strict prefix of the other.
+pub fn assert_chunks_cover<T>(original: &[T], chunks: &[Vec<T>])
+where
+    T: PartialEq + Debug + Clone,
+{
+    let flattened: Vec<T> = chunks.iter().flatten().cloned().collect();
+    if let Some(index) = original
+        .iter()
+        .zip(&flattened)
+        .position(|(expected, actual)| expected != actual)
+    {
+        panic!(
+            "chunks diverge from original at index {}: expected {:?}, found {:?}",
+            index, original[index], flattened[index]
+        );
+    }
+    assert!(
+        flattened.len() == original.len(),
+        "chunks cover {} element(s) but original has {} (length mismatch at index {})",
+        flattened.len(),
+        original.len(),
+        flattened.len().min(original.len())
+    );
+}
+
+/// Assert `b` is a permutation of `a`: the same elements with the same multiplicities.
+///
+/// Unlike a set comparison, `[1, 1, 2]` and `[1, 2, 2]` are rejected, which is what sorts and
+/// shuffles must guarantee. Panics with the first element (in `a` order, then `b` order) whose
+/// counts differ.
+pub fn assert_is_permutation_of<T>(a: &[T], b: &[T])
+where
+    T: Eq + Hash + Debug,
+{
+    fn count<T: Eq + Hash>(values: &[T]) -> HashMap<&T, usize> {
+        let mut counts = HashMap::new();
+        for value in values {
+            *counts.entry(value).or_default() += 1;
+        }
+        counts
+    }
+    let (a_counts, b_counts) = (count(a), count(b));
+
+    for value in a.iter().chain(b) {
+        let in_a = a_counts.get(value).copied().unwrap_or(0);
+        let in_b = b_counts.get(value).copied().unwrap_or(0);
+        assert!(
+            in_a == in_b,
+            "not a permutation: {:?} occurs {} time(s) in the first slice but {} in the second",
+            value,
+            in_a,
+            in_b
+        );
+    }
+}
+
+/// Assert every element of `sub` also occurs in `sup`, e.g. output keys drawn from input keys.
+///
+/// Panics listing each missing element once, in `sub` order.
+pub fn assert_subset<T>(sub: &[T], sup: &[T])
+where
+    T: Eq + Hash + Debug,
+{
+    let sup: HashSet<&T> = sup.iter().collect();
+    let mut seen = HashSet::new();
+    let missing: Vec<&T> = sub
+        .iter()
+        .filter(|value| !sup.contains(value) && seen.insert(*value))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "not a subset: {} element(s) missing from the superset: {:?}",
+        missing.len(),
+        missing
+    );
+}
+
+/// Assert `a` and `b` share no element, e.g. two result buckets that must not overlap.
+///
+/// Panics listing each shared element once, in `a` order.
+pub fn assert_disjoint<T>(a: &[T], b: &[T])
+where
+    T: Eq + Hash + Debug,
+{
+    let b: HashSet<&T> = b.iter().collect();
+    let mut seen = HashSet::new();
+    let shared: Vec<&T> = a
+        .iter()
+        .filter(|value| b.contains(value) && seen.insert(*value))
+        .collect();
+    assert!(
+        shared.is_empty(),
+        "not disjoint: {} shared element(s): {:?}",
+        shared.len(),
+        shared
+    );
+}
+
+/// Assert the element counts of `values` equal `expected` exactly, e.g. for tally functions.
+///
+/// Keys missing from `expected` must not occur at all. Panics listing every key whose observed
+/// count differs, in `expected` order followed by unexpected keys in `values` order.
+pub fn assert_frequency<T>(values: &[T], expected: &[(T, usize)])
+where
+    T: Eq + Hash + Debug,
+{
+    let mut observed: HashMap<&T, usize> = HashMap::new();
+    for value in values {
+        *observed.entry(value).or_default() += 1;
+    }
+    let mut wanted: HashMap<&T, usize> = HashMap::new();
+    for (key, count) in expected {
+        *wanted.entry(key).or_default() += count;
+    }
+
+    let mut reported = HashSet::new();
+    let mismatches: Vec<String> = expected
+        .iter()
+        .map(|(key, _)| key)
+        .chain(values)
+        .filter_map(|key| {
+            let want = wanted.get(key).copied().unwrap_or(0);
+            let got = observed.get(key).copied().unwrap_or(0);
+            (want != got && reported.insert(key))
+                .then(|| format!("{key:?}: expected {want}, observed {got}"))
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "frequency mismatch for {} key(s): {}",
+        mismatches.len(),
+        mismatches.join(", ")
+    );
+}
+
+/// Assert `sort_fn` is deterministic: sorting the same input twice yields the same order.
+///
+/// Both runs receive a clone of `input` and their results are compared through `key` (an element
+/// identity such as an id, not the sort key), so ties must land in the same order every time.
+/// Catches sorts whose tie order depends on `HashMap` iteration, random pivots, or other hidden
+/// state; a stable `sort_by_key` passes. Panics with the first differing position.
+pub fn assert_sort_deterministic<T, K, F, Kf>(input: &[T], sort_fn: F, key: Kf)
+where
+    T: Clone,
+    K: PartialEq + Debug,
+    F: Fn(Vec<T>) -> Vec<T>,
+    Kf: Fn(&T) -> K,
+{
+    let first: Vec<K> = sort_fn(input.to_vec()).iter().map(&key).collect();
+    let second: Vec<K> = sort_fn(input.to_vec()).iter().map(&key).collect();
+
+    assert_eq!(
+        first.len(),
+        second.len(),
+        "sort output length differs between runs"
+    );
+    if let Some(index) = first.iter().zip(&second).position(|(a, b)| a != b) {
+        panic!(
+            "sort is not deterministic: position {} holds {:?} on the first run but {:?} on the second",
+            index, first[index], second[index]
+        );
+    }
+}
+
+/// Assert two (possibly infinite) iterators agree element-wise on their first `max_items` items.
+///
+/// Only the compared window is pulled from each iterator, so a fast lazy generator can be checked
+/// against a reference without materializing either. A stream that ends inside the window while
+/// the other continues is a divergence. Panics with the first differing index.
+pub fn assert_streams_equal<A, B>(a: A, b: B, max_items: usize)
+where
+    A: Iterator,
+    B: Iterator,
+    A::Item: PartialEq<B::Item> + Debug,
+    B::Item: Debug,
+{
+    let mut a = a.fuse();
+    let mut b = b.fuse();
+    for index in 0..max_items {
+        match (a.next(), b.next()) {
+            (None, None) => return,
+            (Some(left), Some(right)) => assert!(
+                left == right,
+                "streams diverge at index {}: {:?} != {:?}",
+                index,
+                left,
+                right
+            ),
+            (Some(left), None) => panic!(
+                "streams diverge at index {}: first stream yields {:?} but second stream ended",
+                index, left
+            ),
+            (None, Some(right)) => panic!(
+                "streams diverge at index {}: first stream ended but second stream yields {:?}",
+                index, right
+            ),
+        }
+    }
+}
+
+/// Assert a map-reduce agrees with its sequential fold however the input is split.
+///
+/// The sequential result folds `map(v)` over `values` from `identity` with `combine`. It is then
+/// recomputed by splitting `values` at each point in `split_points` on its own, and at all of
+/// them at once, folding every chunk from `identity` and combining the chunk results left to
+/// right, as rayon-style reductions do. Catches non-associative combiners and identities that
+/// are not neutral. Panics naming the split that diverged.
+pub fn assert_parallel_reduce_consistent<T, A, Map, Combine>(
+    values: &[T],
+    split_points: &[usize],
+    map: Map,
+    combine: Combine,
+    identity: A,
+) where
+    T: Clone,
+    A: Clone + PartialEq + Debug,
+    Map: Fn(&T) -> A,
+    Combine: Fn(A, A) -> A,
+{
+    let fold = |chunk: &[T]| {
+        chunk
+            .iter()
+            .fold(identity.clone(), |acc, value| combine(acc, map(value)))
+    };
+    let sequential = fold(values);
+
+    let mut all_points: Vec<usize> = split_points.to_vec();
+    all_points.sort_unstable();
+    all_points.dedup();
+    let mut splits: Vec<Vec<usize>> = split_points.iter().map(|&p| vec![p]).collect();
+    if all_points.len() > 1 {
+        splits.push(all_points);
+    }
+
+    for points in splits {
+        assert!(
+            points.iter().all(|&p| p <= values.len()),
+            "split points {:?} exceed input length {}",
+            points,
+            values.len()
+        );
+        let mut bounds = Vec::with_capacity(points.len() + 2);
+        bounds.push(0);
+        bounds.extend(points.iter().copied());
+        bounds.push(values.len());
+        let combined = bounds
+            .windows(2)
+            .map(|w| fold(&values[w[0]..w[1]]))
+            .reduce(&combine)
+            .unwrap_or_else(|| identity.clone());
+        assert!(
+            combined == sequential,
+            "split-combine at {:?} gives {:?} but sequential fold gives {:?}",
+            points,
+            combined,
+            sequential
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Order {
+        id: u32,
+        price: f64,
+    }
+
+    fn orders() -> Vec<Order> {
+        vec![
+            Order { id: 7, price: 10.0 },
+            Order { id: 3, price: 11.5 },
+            Order { id: 9, price: 9.25 },
+        ]
+    }
+
+    #[derive(Debug)]
+    struct Candle {
+        timestamp: i64,
+        close: f64,
+    }
+
+    fn price_cents(order: &Order) -> i64 {
+        (order.price * 100.0).round() as i64
+    }
+
+    #[test]
+    fn orders_sorted_by_price_pass() {
+        let orders = [
+            Order { id: 7, price: 99.5 },
+            Order { id: 3, price: 99.5 },
+            Order {
+                id: 1,
+                price: 101.25,
+            },
+        ];
+        assert_sorted_by_key(&orders, price_cents);
+    }
+
+    #[test]
+    #[should_panic(expected = "not sorted by key at index 2: key 9950 follows key 10125")]
+    fn unsorted_orders_report_index_and_keys() {
+        let orders = [
+            Order { id: 1, price: 99.0 },
+            Order {
+                id: 2,
+                price: 101.25,
+            },
+            Order { id: 3, price: 99.5 },
+        ];
+        assert_sorted_by_key(&orders, price_cents);
+    }
+
+    #[test]
+    fn evens_first_is_partitioned() {
+        assert_partitioned(&[2, 4, 1, 3], |v| v % 2 == 0);
+        assert_partitioned(&[1, 3], |v| v % 2 == 0);
+        assert_partitioned::<i32, _>(&[], |v| v % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "not partitioned: boundary at index 1 (1 fails the predicate) but index 2 (4) satisfies it"
+    )]
+    fn even_after_odd_breaks_partition() {
+        assert_partitioned(&[2, 1, 4], |v| v % 2 == 0);
+    }
+
+    #[test]
+    fn subset_and_disjoint_pass() {
+        assert_subset(&["b", "a", "b"], &["a", "b", "c"]);
+        assert_subset::<u8>(&[], &[1]);
+        assert_disjoint(&[1, 2, 2], &[3, 4]);
+        assert_disjoint::<u8>(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "not a subset: 2 element(s) missing from the superset: [\"x\", \"y\"]"
+    )]
+    fn subset_lists_every_missing_element_once() {
+        assert_subset(&["a", "x", "y", "x"], &["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not disjoint: 2 shared element(s): [3, 1]")]
+    fn disjoint_lists_every_shared_element_once() {
+        assert_disjoint(&[3, 1, 3, 5], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn tally_matches_expected_counts() {
+        assert_frequency(
+            &["buy", "sell", "buy", "hold"],
+            &[("buy", 2), ("sell", 1), ("hold", 1), ("cancel", 0)],
+        );
+        assert_frequency::<u8>(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "frequency mismatch for 2 key(s): \"buy\": expected 2, observed 3, \"hold\": expected 0, observed 1"
+    )]
+    fn over_counted_and_unexpected_keys_are_reported() {
+        assert_frequency(
+            &["buy", "sell", "buy", "hold", "buy"],
+            &[("buy", 2), ("sell", 1)],
+        );
+    }
+
+    #[test]
+    fn non_adjacent_repeats_pass() {
+        assert_no_adjacent_duplicates(&[1, 2, 1, 2]);
+        assert_no_adjacent_duplicates::<u8>(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent duplicate at index 1: 1 repeats index 0")]
+    fn adjacent_repeat_fails() {
+        assert_no_adjacent_duplicates(&[1, 1, 2]);
+    }
+
+    #[test]
+    fn std_chunks_cover_the_original() {
+        let original: Vec<u32> = (0..10).collect();
+        let chunks: Vec<Vec<u32>> = original.chunks(3).map(<[u32]>::to_vec).collect();
+        assert_chunks_cover(&original, &chunks);
+        assert_chunks_cover::<u32>(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunks diverge from original at index 3: expected 3, found 4")]
+    fn dropped_middle_element_is_reported() {
+        assert_chunks_cover(&[0, 1, 2, 3, 4, 5], &[vec![0, 1, 2], vec![4, 5]]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "chunks cover 5 element(s) but original has 6 (length mismatch at index 5)"
+    )]
+    fn dropped_tail_is_a_length_mismatch() {
+        assert_chunks_cover(&[0, 1, 2, 3, 4, 5], &[vec![0, 1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn candles_sorted_by_timestamp_pass() {
+        let candles = [
+            Candle {
+                timestamp: 1_000,
+                close: 10.5,
+            },
+            Candle {
+                timestamp: 1_000,
+                close: 9.0,
+            },
+            Candle {
+                timestamp: 2_000,
+                close: 11.0,
+            },
+        ];
+        assert_sorted_by(&candles, |a, b| a.timestamp.cmp(&b.timestamp));
+        assert_sorted_by(&candles[1..], |a, b| a.close.total_cmp(&b.close));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "not sorted: element 1 Candle { timestamp: 3000, close: 1.0 } \
+                               should not precede element 2 Candle { timestamp: 2000, close: 2.0 }"
+    )]
+    fn out_of_order_candles_name_both_elements() {
+        let candles = [
+            Candle {
+                timestamp: 1_000,
+                close: 0.0,
+            },
+            Candle {
+                timestamp: 3_000,
+                close: 1.0,
+            },
+            Candle {
+                timestamp: 2_000,
+                close: 2.0,
+            },
+        ];
+        assert_sorted_by(&candles, |a, b| a.timestamp.cmp(&b.timestamp));
+    }
+
+    #[test]
+    fn sort_output_is_a_permutation_of_input() {
+        let input = [3, 1, 3, 2, 1];
+        let mut sorted = input;
+        sorted.sort_unstable();
+        assert_is_permutation_of(&input, &sorted);
+        assert_is_permutation_of::<u8>(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "not a permutation: 1 occurs 2 time(s) in the first slice but 1 in the second"
+    )]
+    fn multiplicity_mismatch_fails_where_set_equality_passes() {
+        let (a, b) = ([1, 1, 2], [1, 2, 2]);
+        assert_eq!(
+            a.iter().collect::<HashSet<_>>(),
+            b.iter().collect::<HashSet<_>>()
+        );
+        assert_is_permutation_of(&a, &b);
+    }
+
+    #[test]
+    fn id_preserving_map_passes() {
+        let input = orders();
+        let mut output: Vec<(u32, f64)> = input.iter().map(|o| (o.id, o.price * 2.0)).collect();
+        output.reverse();
+        assert_key_preserved(&input, &output, |o| o.id, |(id, _)| *id);
+    }
+
+    #[test]
+    #[should_panic(expected = "key 3 dropped by transformation")]
+    fn dropped_element_is_reported() {
+        let input = orders();
+        let output: Vec<Order> = input.iter().filter(|o| o.price < 11.0).cloned().collect();
+        assert_key_preserved(&input, &output, |o| o.id, |o| o.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "key 9 added by transformation (1 extra occurrence(s) in output)")]
+    fn duplicated_element_is_reported() {
+        let input = orders();
+        let mut output = input.clone();
+        output.push(input[2].clone());
+        assert_key_preserved(&input, &output, |o| o.id, |o| o.id);
+    }
+
+    #[test]
+    fn stable_sort_by_key_with_ties_is_deterministic() {
+        let mut input = orders();
+        input.push(Order { id: 1, price: 10.0 });
+        assert_sort_deterministic(
+            &input,
+            |mut v| {
+                v.sort_by_key(price_cents);
+                v
+            },
+            |o| o.id,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "sort is not deterministic: position 1")]
+    fn tie_order_varying_between_runs_is_detected() {
+        let mut input = orders();
+        input.push(Order { id: 1, price: 10.0 });
+        // Stands in for hidden state such as HashMap iteration: ties flip on every call.
+        let calls = std::cell::Cell::new(0);
+        assert_sort_deterministic(
+            &input,
+            |mut v| {
+                calls.set(calls.get() + 1);
+                if calls.get() % 2 == 0 {
+                    v.reverse();
+                }
+                v.sort_by(|a, b| a.price.total_cmp(&b.price));
+                v
+            },
+            |o| o.id,
+        );
+    }
+
+    #[test]
+    fn lazy_generator_matches_reference_within_window() {
+        let powers = std::iter::successors(Some(1_u64), |p| p.checked_mul(2));
+        let reference = (0..).map(|n| 1_u64 << n);
+        assert_streams_equal(powers, reference, 64);
+        assert_streams_equal(0..3, vec![0, 1, 2].into_iter(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "streams diverge at index 3: 6 != 7")]
+    fn divergent_element_is_reported() {
+        assert_streams_equal((0..).map(|n| n * 2), [0, 2, 4, 7].into_iter(), 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "streams diverge at index 2: first stream ended but second stream yields 2"
+    )]
+    fn early_end_inside_window_is_reported() {
+        assert_streams_equal(0..2, 0.., 5);
+    }
+
+    #[test]
+    fn sum_reduces_consistently_at_every_split() {
+        let values: Vec<i64> = (1..=10).collect();
+        assert_parallel_reduce_consistent(&values, &[0, 3, 5, 10], |v| v * v, |a, b| a + b, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "split-combine at [1] gives 4 but sequential fold gives -6")]
+    fn non_associative_combine_is_reported() {
+        assert_parallel_reduce_consistent(&[1_i64, 2, 3], &[1], |v| *v, |a, b| a - b, 0);
+    }
+}
@@ -0,0 +1,69 @@
+//! CRDT merge-law assertions for replicated state.
+
+use std::fmt::Debug;
+
+/// Assert `merge` satisfies the three state-based CRDT laws over `a`, `b` and `c`.
+///
+/// Checks commutativity on every pair, associativity on `(a, b, c)`, and idempotency on each
+/// value. Replicas only converge when all three hold. Panics naming the first law that fails
+/// and the operands involved.
+pub fn assert_crdt_merge<T, F>(a: T, b: T, c: T, merge: F)
+where
+    T: Clone + PartialEq + Debug,
+    F: Fn(T, T) -> T,
+{
+    for (x, y) in [(&a, &b), (&b, &c), (&a, &c)] {
+        let xy = merge(x.clone(), y.clone());
+        let yx = merge(y.clone(), x.clone());
+        assert!(
+            xy == yx,
+            "CRDT merge is not commutative: merge({x:?}, {y:?}) = {xy:?} but merge({y:?}, {x:?}) = {yx:?}"
+        );
+    }
+
+    let left = merge(merge(a.clone(), b.clone()), c.clone());
+    let right = merge(a.clone(), merge(b.clone(), c.clone()));
+    assert!(
+        left == right,
+        "CRDT merge is not associative for ({a:?}, {b:?}, {c:?}): merge(merge(a, b), c) = {left:?} but merge(a, merge(b, c)) = {right:?}"
+    );
+
+    for x in [&a, &b, &c] {
+        let merged = merge(x.clone(), x.clone());
+        assert!(
+            merged == *x,
+            "CRDT merge is not idempotent: merge({x:?}, {x:?}) = {merged:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Last-write-wins register that keeps the left operand on timestamp ties.
+    fn lww_without_tiebreak(a: (u64, char), b: (u64, char)) -> (u64, char) {
+        if b.0 > a.0 { b } else { a }
+    }
+
+    proptest! {
+        #[test]
+        fn max_register_is_a_crdt(a in any::<u64>(), b in any::<u64>(), c in any::<u64>()) {
+            assert_crdt_merge(a, b, c, u64::max);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "CRDT merge is not commutative: merge((5, 'x'), (5, 'y'))")]
+    fn last_write_wins_without_tiebreak_is_not_commutative() {
+        assert_crdt_merge((5, 'x'), (5, 'y'), (7, 'z'), lww_without_tiebreak);
+    }
+
+    #[test]
+    #[should_panic(expected = "CRDT merge is not idempotent")]
+    fn counter_sum_is_not_idempotent() {
+        assert_crdt_merge(1_u32, 1, 1, |x, y| x + y);
+    }
+}
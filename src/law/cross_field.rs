@@ -0,0 +1,194 @@
+//! Cross-field invariant assertions for structs.
+
+/// Short type name (last path segment, generics preserved) used in invariant messages.
+#[doc(hidden)]
+pub fn short_type_name<T: ?Sized>(_value: &T) -> &'static str {
+    let full = std::any::type_name::<T>();
+    let base_end = full.find('<').unwrap_or(full.len());
+    let start = full[..base_end].rfind("::").map_or(0, |idx| idx + 2);
+    &full[start..]
+}
+
+/// Assert a list of cross-field invariants over a struct value.
+///
+/// The value is borrowed under the given binding and every clause is checked in order. On
+/// failure the panic message names the struct type and the violated clause.
+///
+/// ```
+/// use kitchensink_testing::assert_invariants;
+///
+/// #[derive(Debug)]
+/// struct Order {
+///     quantity: f64,
+///     filled: f64,
+///     price: f64,
+/// }
+///
+/// let order = Order { quantity: 2.0, filled: 1.5, price: 101.25 };
+/// assert_invariants!(order, |o| {
+///     o.filled <= o.quantity,
+///     o.price > 0.0,
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_invariants {
+    ($value:expr, |$binding:ident| { $($clause:expr),+ $(,)? }) => {{
+        let $binding = &$value;
+        $(
+            assert!(
+                $clause,
+                "{} invariant violated: `{}` (value: {:?})",
+                $crate::law::cross_field::short_type_name($binding),
+                stringify!($clause),
+                $binding
+            );
+        )+
+    }};
+}
+
+/// Run every law assertion over a value and report all failures in one panic.
+///
+/// Like [`assert_invariants!`], but each clause is a panicking assertion call (any law from
+/// this crate, or `assert!`) rather than a boolean. All clauses run even after one fails, and
+/// the panic lists every failed clause with its message.
+///
+/// ```
+/// use kitchensink_testing::law::invariants::{assert_bounded, assert_within_percent};
+/// use kitchensink_testing::pbt_assert_all;
+///
+/// #[derive(Debug)]
+/// struct Order {
+///     quantity: f64,
+///     filled: f64,
+///     price: f64,
+/// }
+///
+/// let order = Order { quantity: 2.0, filled: 1.5, price: 101.25 };
+/// pbt_assert_all!(order, |o| {
+///     assert_bounded(o.filled, 0.0, o.quantity),
+///     assert_within_percent(o.price, 100.0, 5.0),
+/// });
+/// ```
+#[macro_export]
+macro_rules! pbt_assert_all {
+    ($value:expr, |$binding:ident| { $($law:expr),+ $(,)? }) => {{
+        let $binding = &$value;
+        let mut failures: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let mut total = 0_usize;
+        $(
+            total += 1;
+            if let ::std::option::Option::Some(message) =
+                $crate::law::panics::capture_panic(|| { $law; })
+            {
+                failures.push(::std::format!("`{}`: {}", stringify!($law), message));
+            }
+        )+
+        if !failures.is_empty() {
+            ::std::panic!(
+                "{}: {} of {} law(s) failed:\n  {}",
+                $crate::law::cross_field::short_type_name($binding),
+                failures.len(),
+                total,
+                failures.join("\n  ")
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    struct Order {
+        quantity: f64,
+        filled: f64,
+        price: f64,
+    }
+
+    #[derive(Debug)]
+    struct Candle {
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    }
+
+    fn assert_order_valid(order: &Order) {
+        assert_invariants!(order, |o| {
+            o.filled <= o.quantity,
+            o.filled >= 0.0,
+            o.price > 0.0,
+        });
+    }
+
+    fn assert_candle_valid(candle: &Candle) {
+        assert_invariants!(candle, |c| {
+            c.high >= c.open.max(c.close),
+            c.low <= c.open.min(c.close),
+            c.low <= c.high,
+            c.volume >= 0.0,
+        });
+    }
+
+    #[test]
+    fn batched_laws_report_every_failure_together() {
+        use crate::law::invariants::{assert_bounded, assert_within_percent};
+        use crate::law::panics::assert_panics_with;
+
+        let order = Order {
+            quantity: 1.0,
+            filled: 1.5,
+            price: 99.5,
+        };
+        let check = || {
+            pbt_assert_all!(order, |o| {
+                assert_bounded(o.filled, 0.0, o.quantity),
+                assert_within_percent(o.price, 100.0, 1.0),
+                assert!(o.price > 0.0, "price must be positive"),
+            });
+        };
+        assert_panics_with("Order: 1 of 3 law(s) failed", check);
+        assert_panics_with(
+            "`assert_bounded(o.filled, 0.0, o.quantity)`: value 1.5 outside bounds [0.0, 1.0]",
+            check,
+        );
+    }
+
+    #[test]
+    fn valid_structs_pass_all_clauses() {
+        assert_order_valid(&Order {
+            quantity: 10.0,
+            filled: 10.0,
+            price: 99.5,
+        });
+        assert_candle_valid(&Candle {
+            open: 10.0,
+            high: 12.0,
+            low: 9.5,
+            close: 11.0,
+            volume: 1_000.0,
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Order invariant violated: `o.filled <= o.quantity`")]
+    fn overfilled_order_names_struct_and_clause() {
+        assert_order_valid(&Order {
+            quantity: 1.0,
+            filled: 1.5,
+            price: 99.5,
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Candle invariant violated: `c.low <= c.open.min(c.close)`")]
+    fn candle_low_above_body_names_struct_and_clause() {
+        assert_candle_valid(&Candle {
+            open: 10.0,
+            high: 12.0,
+            low: 10.5,
+            close: 11.0,
+            volume: 1_000.0,
+        });
+    }
+}
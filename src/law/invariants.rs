@@ -2,6 +2,8 @@
 
 use std::fmt::Debug;
 
+use thiserror::Error;
+
 /// Assert monotonically increasing sequence.
 pub fn assert_monotonic_increasing<T>(values: &[T])
 where
@@ -17,20 +19,165 @@ where
     }
 }
 
+/// Assert a sequence never decreases; equal neighbours (plateaus) are allowed.
+///
+/// Suits cumulative sums and running totals. Panics with the first decrease and its index.
+pub fn assert_monotonic_non_decreasing<T>(values: &[T])
+where
+    T: PartialOrd + Debug,
+{
+    for (offset, window) in values.windows(2).enumerate() {
+        assert!(
+            window[1] >= window[0],
+            "sequence decreases at index {}: {:?} < {:?}",
+            offset + 1,
+            window[1],
+            window[0]
+        );
+    }
+}
+
+/// Assert a sequence never increases; equal neighbours (plateaus) are allowed.
+pub fn assert_monotonic_non_increasing<T>(values: &[T])
+where
+    T: PartialOrd + Debug,
+{
+    for (offset, window) in values.windows(2).enumerate() {
+        assert!(
+            window[1] <= window[0],
+            "sequence increases at index {}: {:?} > {:?}",
+            offset + 1,
+            window[1],
+            window[0]
+        );
+    }
+}
+
+/// Assert a sorted timestamp series contains no duplicates.
+///
+/// Relies on ordering for an O(n) adjacent scan instead of hashing; a series that is not
+/// sorted ascending is rejected as well. Panics with the first duplicate and its index.
+pub fn assert_unique_timestamps(timestamps: &[i64]) {
+    for (offset, window) in timestamps.windows(2).enumerate() {
+        let index = offset + 1;
+        assert!(
+            window[1] != window[0],
+            "duplicate timestamp {} at index {} (same as index {})",
+            window[1],
+            index,
+            offset
+        );
+        assert!(
+            window[1] > window[0],
+            "timestamps not sorted: {} at index {} precedes {}",
+            window[0],
+            offset,
+            window[1]
+        );
+    }
+}
+
+/// Maximum number of violations listed by [`assert_all_in_range`].
+const MAX_LISTED_VIOLATIONS: usize = 10;
+
 /// Assert all values are inside `[min, max]`.
+///
+/// Scans the whole slice and panics once with the total count and the first ten offending
+/// `index: value` pairs.
 pub fn assert_all_in_range<T>(values: &[T], min: T, max: T)
 where
     T: PartialOrd + Debug + Copy,
 {
-    for value in values {
-        assert!(
-            *value >= min && *value <= max,
-            "value {:?} outside range [{:?}, {:?}]",
-            value,
-            min,
-            max
-        );
+    let violations: Vec<(usize, &T)> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !(**value >= min && **value <= max))
+        .collect();
+    if violations.is_empty() {
+        return;
     }
+
+    let listed: Vec<String> = violations
+        .iter()
+        .take(MAX_LISTED_VIOLATIONS)
+        .map(|(index, value)| format!("index {index}: {value:?}"))
+        .collect();
+    let more = violations.len().saturating_sub(MAX_LISTED_VIOLATIONS);
+    panic!(
+        "{} of {} value(s) outside range [{:?}, {:?}]: {}{}",
+        violations.len(),
+        values.len(),
+        min,
+        max,
+        listed.join(", "),
+        if more > 0 {
+            format!(", ... and {more} more")
+        } else {
+            String::new()
+        }
+    );
+}
+
+/// Error returned by [`check_bounded`] for a value outside `[min, max]`.
+///
+/// Operands are rendered with `Debug` so the error is independent of the checked type.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("value {value} outside bounds [{min}, {max}]")]
+pub struct BoundsError {
+    /// The offending value.
+    pub value: String,
+    /// Inclusive lower bound.
+    pub min: String,
+    /// Inclusive upper bound.
+    pub max: String,
+}
+
+/// Check `value` lies inside the inclusive range `[min, max]`.
+///
+/// Non-panicking counterpart to [`assert_bounded`] for use inside `prop_assert!`, so proptest
+/// shrinks the failure directly. Incomparable values (e.g. `NaN`) are out of bounds.
+pub fn check_bounded<T>(value: T, min: T, max: T) -> Result<(), BoundsError>
+where
+    T: PartialOrd + Debug,
+{
+    if value >= min && value <= max {
+        return Ok(());
+    }
+    Err(BoundsError {
+        value: format!("{value:?}"),
+        min: format!("{min:?}"),
+        max: format!("{max:?}"),
+    })
+}
+
+/// Assert `value` lies inside the inclusive range `[min, max]`.
+pub fn assert_bounded<T>(value: T, min: T, max: T)
+where
+    T: PartialOrd + Debug,
+{
+    check_bounded(value, min, max).unwrap_or_else(|err| panic!("{err}"));
+}
+
+/// Assert `value` lies inside the half-open range `[min, max)`, as for indices and price bands.
+pub fn assert_bounded_exclusive<T>(value: T, min: T, max: T)
+where
+    T: PartialOrd + Debug,
+{
+    assert!(
+        value >= min && value < max,
+        "value {value:?} outside bounds [{min:?}, {max:?})"
+    );
+}
+
+/// Assert `value` lies strictly inside the open range `(min, max)`.
+pub fn assert_bounded_open<T>(value: T, min: T, max: T)
+where
+    T: PartialOrd + Debug,
+{
+    assert!(
+        value > min && value < max,
+        "value {value:?} outside bounds ({min:?}, {max:?})"
+    );
 }
 
 /// Assert approximate equality with absolute tolerance.
@@ -45,9 +192,252 @@ pub fn assert_approx_eq(left: f64, right: f64, tolerance: f64) {
     );
 }
 
+/// Assert approximate equality with a tolerance relative to the larger magnitude.
+///
+/// Unlike [`assert_approx_eq`], whose absolute tolerance is meaningless across scales, this
+/// passes when `|a - b| <= rel_tolerance * max(|a|, |b|)`, so `1e-4` means "within 0.01%"
+/// for both 0.5 and 95_000.0. Two zeros compare equal.
+pub fn assert_approx_eq_relative(a: f64, b: f64, rel_tolerance: f64) {
+    assert!(
+        rel_tolerance >= 0.0,
+        "relative tolerance must be non-negative"
+    );
+    let scale = a.abs().max(b.abs());
+    assert!(
+        a == b || (a - b).abs() <= rel_tolerance * scale,
+        "{a} and {b} differ by more than {rel_tolerance} relative to {scale}"
+    );
+}
+
+/// Assert `actual` is within `percent` percent of `expected`, as in reconciliation checks.
+///
+/// The band is `|actual - expected| <= |expected| * percent / 100`, so an `expected` of zero
+/// only accepts an `actual` of zero. Panics with the observed deviation in percent.
+pub fn assert_within_percent(actual: f64, expected: f64, percent: f64) {
+    assert!(percent >= 0.0, "percent must be non-negative");
+    if expected == 0.0 {
+        assert!(
+            actual == 0.0,
+            "{actual} is not within {percent}% of 0 (only 0 is)"
+        );
+        return;
+    }
+    let deviation = (actual - expected).abs() / expected.abs() * 100.0;
+    assert!(
+        deviation <= percent,
+        "{actual} deviates {deviation:.4}% from {expected}, more than {percent}%"
+    );
+}
+
+/// Assert `a` and `b` are at most `max_ulps` representable doubles apart.
+///
+/// The robust choice for oracle-vs-optimized float math: the bit patterns are mapped onto a
+/// single ordered integer line, so the distance is correct across the sign boundary and
+/// `-0.0` and `0.0` are zero apart. NaN is never close to anything.
+pub fn assert_approx_eq_ulps(a: f64, b: f64, max_ulps: u64) {
+    assert!(
+        !a.is_nan() && !b.is_nan(),
+        "cannot compare NaN by ULP distance: {a} vs {b}"
+    );
+    let distance = (ordered_bits(a) - ordered_bits(b)).unsigned_abs();
+    assert!(
+        distance <= u128::from(max_ulps),
+        "{a:e} and {b:e} are {distance} ULPs apart, more than {max_ulps}"
+    );
+}
+
+/// Bit pattern of `x` as a position on a line ordered like the floats themselves.
+fn ordered_bits(x: f64) -> i128 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 {
+        i128::from(i64::MIN) - i128::from(bits)
+    } else {
+        i128::from(bits)
+    }
+}
+
+/// Assert `round_fn` rounds every value to the nearest integer with ties to even.
+///
+/// Financial rounding is banker's rounding, while `f64::round` breaks ties away from zero;
+/// include half-way points such as `0.5`, `1.5`, `2.5` and `-2.5` in `values`. Panics with
+/// the first value whose result differs.
+pub fn assert_rounds_half_to_even<F>(values: &[f64], round_fn: F)
+where
+    F: Fn(f64) -> f64,
+{
+    for &value in values {
+        let expected = value.round_ties_even();
+        let actual = round_fn(value);
+        assert!(
+            actual == expected || (actual.is_nan() && expected.is_nan()),
+            "rounding {value} gave {actual}, expected {expected} (half to even)"
+        );
+    }
+}
+
+/// Assert the empirical mean and sample standard deviation of `values` fall in inclusive ranges.
+///
+/// A smoke test for distribution-shaped generators (e.g. that a volatility parameter actually
+/// widens the spread); use a large sample so the ranges can stay tight. Panics with the
+/// computed statistics.
+pub fn assert_sample_stats(values: &[f64], mean_range: (f64, f64), std_range: (f64, f64)) {
+    assert!(
+        values.len() >= 2,
+        "sample statistics need at least two values, got {}",
+        values.len()
+    );
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std = variance.sqrt();
+
+    assert!(
+        mean >= mean_range.0 && mean <= mean_range.1,
+        "sample mean {mean} outside [{}, {}] (std {std}, n {})",
+        mean_range.0,
+        mean_range.1,
+        values.len()
+    );
+    assert!(
+        std >= std_range.0 && std <= std_range.1,
+        "sample std {std} outside [{}, {}] (mean {mean}, n {})",
+        std_range.0,
+        std_range.1,
+        values.len()
+    );
+}
+
+/// Assert the Pearson correlation of `a` and `b` is within `tol` of `expected`.
+///
+/// Panics when the slices differ in length, hold fewer than two values, or either has zero
+/// variance (correlation is undefined there).
+pub fn assert_correlation(a: &[f64], b: &[f64], expected: f64, tol: f64) {
+    assert!(tol >= 0.0, "tolerance must be non-negative");
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "correlation needs equal-length sequences ({} vs {})",
+        a.len(),
+        b.len()
+    );
+    assert!(a.len() >= 2, "correlation needs at least two samples");
+
+    let n = a.len() as f64;
+    let mean_a = a.iter().sum::<f64>() / n;
+    let mean_b = b.iter().sum::<f64>() / n;
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        let (dx, dy) = (x - mean_a, y - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+    assert!(
+        var_a > 0.0 && var_b > 0.0,
+        "correlation is undefined: {} sequence has zero variance",
+        if var_a > 0.0 { "second" } else { "first" }
+    );
+
+    let actual = cov / (var_a.sqrt() * var_b.sqrt());
+    assert!(
+        (actual - expected).abs() <= tol,
+        "correlation {} differs from expected {} by more than {}",
+        actual,
+        expected,
+        tol
+    );
+}
+
+/// Assert `f` is order-preserving: a strictly larger `key` never maps to a smaller output.
+///
+/// Inputs are sorted by `key` and the outputs of `f` must then be non-decreasing, which is what
+/// scoring and ranking functions promise. Inputs with equal keys are not compared with each
+/// other. Keys must be totally ordered; a key that is not comparable with itself (a float NaN)
+/// is rejected up front. Panics with the first inversion found in key order.
+pub fn assert_monotone<T, U, V, F, K>(values: &[T], f: F, key: K)
+where
+    T: Debug,
+    U: PartialOrd + Debug,
+    V: PartialOrd + Debug,
+    F: Fn(&T) -> V,
+    K: Fn(&T) -> U,
+{
+    let keys: Vec<U> = values.iter().map(&key).collect();
+    if let Some(index) = keys.iter().position(|k| k.partial_cmp(k).is_none()) {
+        panic!(
+            "key {:?} of {:?} at index {} is not comparable (NaN?); monotonicity needs totally ordered keys",
+            keys[index], values[index], index
+        );
+    }
+    let outputs: Vec<V> = values.iter().map(&f).collect();
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| {
+        keys[a].partial_cmp(&keys[b]).unwrap_or_else(|| {
+            panic!(
+                "keys {:?} and {:?} are not comparable; monotonicity needs totally ordered keys",
+                keys[a], keys[b]
+            )
+        })
+    });
+
+    // Largest output among strictly smaller keys, and among the current key group.
+    let mut lower_max: Option<usize> = None;
+    let mut group_max: Option<usize> = None;
+    let mut group_key: Option<usize> = None;
+    let larger = |a: Option<usize>, b: usize| match a {
+        Some(a) if outputs[a] >= outputs[b] => a,
+        _ => b,
+    };
+
+    for &index in &order {
+        if group_key.is_some_and(|g| keys[g] < keys[index]) {
+            lower_max = group_max.map(|g| larger(lower_max, g));
+            group_max = None;
+        }
+        group_key = Some(index);
+
+        if let Some(lower) = lower_max {
+            assert!(
+                outputs[index] >= outputs[lower],
+                "function is not monotone: {:?} (key {:?}) -> {:?} but {:?} (key {:?}) -> {:?}",
+                values[lower],
+                keys[lower],
+                outputs[lower],
+                values[index],
+                keys[index],
+                outputs[index]
+            );
+        }
+        group_max = Some(larger(group_max, index));
+    }
+}
+
+/// Assert `f` is pure over `inputs`: two calls with the same input give equal outputs.
+///
+/// A cheap guard against functions that read hidden mutable state, globals, or the clock.
+/// Panics with the first input whose two outputs differ.
+pub fn assert_pure<I, O, F>(inputs: &[I], mut f: F)
+where
+    I: Clone + Debug,
+    O: PartialEq + Debug,
+    F: FnMut(I) -> O,
+{
+    for (index, input) in inputs.iter().enumerate() {
+        let first = f(input.clone());
+        let second = f(input.clone());
+        assert!(
+            first == second,
+            "function is not pure: input {input:?} at index {index} gave {first:?} then {second:?}"
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
+    use crate::law::panics::assert_panics_with;
 
     #[test]
     fn range_and_monotonic_examples() {
@@ -55,4 +445,267 @@ mod tests {
         assert_all_in_range(&[0.1_f64, 0.2, 0.9], 0.0, 1.0);
         assert_approx_eq(1.0, 1.000_1, 0.001);
     }
+
+    #[test]
+    fn all_out_of_range_indices_are_reported_together() {
+        assert_panics_with(
+            "3 of 5 value(s) outside range [0, 10]: index 1: 11, index 3: -1, index 4: 99",
+            || assert_all_in_range(&[5, 11, 0, -1, 99], 0, 10),
+        );
+
+        let many: Vec<i32> = (0..25).collect();
+        assert_panics_with("index 9: 9, ... and 15 more", || {
+            assert_all_in_range(&many, 100, 200)
+        });
+    }
+
+    #[test]
+    fn plateaus_pass_non_strict_monotonic_checks() {
+        assert_monotonic_non_decreasing(&[1, 1, 2, 2, 3]);
+        assert_monotonic_non_increasing(&[3.0, 3.0, 2.5, 2.5, -1.0]);
+        assert_monotonic_non_decreasing::<i32>(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sequence is not strictly increasing: 1 !> 1")]
+    fn plateau_fails_strict_check() {
+        assert_monotonic_increasing(&[1, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sequence decreases at index 3: 1 < 2")]
+    fn decrease_fails_non_decreasing() {
+        assert_monotonic_non_decreasing(&[1, 1, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sequence increases at index 1: 4 > 3")]
+    fn increase_fails_non_increasing() {
+        assert_monotonic_non_increasing(&[3, 4]);
+    }
+
+    #[test]
+    fn check_bounded_accepts_both_inclusive_boundaries() {
+        assert_eq!(check_bounded(0, 0, 10), Ok(()));
+        assert_eq!(check_bounded(10, 0, 10), Ok(()));
+        assert_eq!(check_bounded(0.5_f64, 0.0, 1.0), Ok(()));
+        assert_bounded(7_u8, 7, 7);
+    }
+
+    #[test]
+    fn check_bounded_reports_value_and_bounds() {
+        assert_eq!(
+            check_bounded(-1, 0, 10),
+            Err(BoundsError {
+                value: "-1".to_string(),
+                min: "0".to_string(),
+                max: "10".to_string(),
+            })
+        );
+        let err = check_bounded(11, 0, 10).expect_err("11 is above the range");
+        assert_eq!(err.to_string(), "value 11 outside bounds [0, 10]");
+        let err = check_bounded(f64::NAN, 0.0, 1.0).expect_err("NaN is never in range");
+        assert_eq!(err.value, "NaN");
+    }
+
+    #[test]
+    #[should_panic(expected = "value \"z\" outside bounds [\"a\", \"m\"]")]
+    fn assert_bounded_panics_with_the_check_error() {
+        assert_bounded("z", "a", "m");
+    }
+
+    #[test]
+    fn exclusive_and_open_bounds_accept_interior_values() {
+        assert_bounded_exclusive(0, 0, 10);
+        assert_bounded_exclusive(9, 0, 10);
+        assert_bounded_open(0.001_f64, 0.0, 1.0);
+        assert_bounded_open(5, 0, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "value 10 outside bounds [0, 10)")]
+    fn exclusive_bound_rejects_max() {
+        assert_bounded(10, 0, 10);
+        assert_bounded_exclusive(10, 0, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "value 1.0 outside bounds (0.0, 1.0)")]
+    fn open_bound_rejects_max() {
+        assert_bounded(1.0_f64, 0.0, 1.0);
+        assert_bounded_open(1.0_f64, 0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "value 0 outside bounds (0, 10)")]
+    fn open_bound_rejects_min() {
+        assert_bounded_exclusive(0, 0, 10);
+        assert_bounded_open(0, 0, 10);
+    }
+
+    #[test]
+    fn relative_tolerance_scales_with_magnitude() {
+        assert_approx_eq_relative(100_000.0, 100_001.0, 1e-4);
+        assert_approx_eq_relative(0.0, 0.0, 0.0);
+        assert_approx_eq_relative(-0.0, 0.0, 0.0);
+        assert_approx_eq_relative(-2.0, -2.000_1, 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "100000 and 100001 differ more than 0.001")]
+    fn absolute_tolerance_rejects_the_same_large_pair() {
+        assert_approx_eq(100_000.0, 100_001.0, 0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "0.001 and 0.0011 differ by more than 0.0001 relative to 0.0011")]
+    fn relative_tolerance_rejects_small_values_far_apart() {
+        assert_approx_eq(0.001, 0.0011, 0.001);
+        assert_approx_eq_relative(0.001, 0.0011, 1e-4);
+    }
+
+    #[test]
+    fn within_percent_accepts_deviation_inside_band() {
+        assert_within_percent(99.0, 100.0, 2.0);
+        assert_within_percent(-101.0, -100.0, 1.0);
+        assert_within_percent(0.0, 0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "99 deviates 1.0000% from 100, more than 0.5%")]
+    fn within_percent_reports_actual_deviation() {
+        assert_within_percent(99.0, 100.0, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "0.001 is not within 50% of 0")]
+    fn zero_expected_requires_zero_actual() {
+        assert_within_percent(0.001, 0.0, 50.0);
+    }
+
+    #[test]
+    fn ulp_distance_counts_adjacent_doubles() {
+        let one_up = f64::from_bits(1.0_f64.to_bits() + 1);
+        let two_up = f64::from_bits(1.0_f64.to_bits() + 2);
+        assert_approx_eq_ulps(1.0, 1.0, 0);
+        assert_approx_eq_ulps(1.0, one_up, 1);
+        assert_approx_eq_ulps(two_up, 1.0, 2);
+        assert_approx_eq_ulps(-0.0, 0.0, 0);
+        assert_approx_eq_ulps(-f64::from_bits(1), f64::from_bits(1), 2);
+        assert_approx_eq_ulps(f64::MAX, f64::INFINITY, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "are 2 ULPs apart, more than 1")]
+    fn two_ulps_apart_fails_a_one_ulp_budget() {
+        assert_approx_eq_ulps(1.0, f64::from_bits(1.0_f64.to_bits() + 2), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot compare NaN")]
+    fn nan_is_never_ulp_close() {
+        assert_approx_eq_ulps(f64::NAN, f64::NAN, u64::MAX);
+    }
+
+    const HALF_WAY: [f64; 8] = [0.5, 1.5, 2.5, 3.5, -0.5, -1.5, -2.5, 2.4];
+
+    #[test]
+    fn bankers_rounding_passes_half_way_points() {
+        let bankers = |x: f64| {
+            let floor = x.floor();
+            match x - floor {
+                diff if diff < 0.5 => floor,
+                diff if diff > 0.5 => floor + 1.0,
+                _ if floor % 2.0 == 0.0 => floor,
+                _ => floor + 1.0,
+            }
+        };
+        assert_rounds_half_to_even(&HALF_WAY, bankers);
+    }
+
+    #[test]
+    #[should_panic(expected = "rounding 0.5 gave 1, expected 0 (half to even)")]
+    fn round_half_away_from_zero_fails_at_one_half() {
+        assert_rounds_half_to_even(&HALF_WAY, f64::round);
+    }
+
+    #[test]
+    fn uniform_sample_has_expected_mean_and_std() {
+        // U(0, 1): mean 0.5, std 1/sqrt(12) ~= 0.2887.
+        let values = crate::bench::sample_n(0.0_f64..1.0, 20_000, 11);
+        assert_sample_stats(&values, (0.49, 0.51), (0.28, 0.30));
+    }
+
+    #[test]
+    #[should_panic(expected = "sample std 0 outside [0.1, 1]")]
+    fn constant_sample_fails_std_range() {
+        assert_sample_stats(&[3.0; 100], (2.9, 3.1), (0.1, 1.0));
+    }
+
+    #[test]
+    fn pure_function_passes() {
+        assert_pure(&[0_i64, -3, 7, i64::MAX], |x| x.wrapping_mul(31) ^ 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "function is not pure: input 2 at index 0 gave 2 then 3")]
+    fn counter_reading_function_is_reported() {
+        let mut calls = 0;
+        assert_pure(&[2_u32, 4], |x| {
+            calls += 1;
+            x + calls - 1
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn clamped_values_check_bounded(x in any::<i32>()) {
+            prop_assert!(check_bounded(x.clamp(-100, 100), -100, 100).is_ok());
+        }
+    }
+
+    #[test]
+    fn correlation_examples() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+        assert_correlation(&a, &[10.0, 20.0, 30.0, 40.0], 1.0, 1e-12);
+        assert_correlation(&a, &[4.0, 3.0, 2.0, 1.0], -1.0, 1e-12);
+        assert_correlation(&a, &[1.0, -1.0, -1.0, 1.0], 0.0, 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "second sequence has zero variance")]
+    fn constant_sequence_has_undefined_correlation() {
+        assert_correlation(&[1.0, 2.0, 3.0], &[5.0, 5.0, 5.0], 0.0, 0.1);
+    }
+
+    #[test]
+    fn clean_timestamp_series_is_unique() {
+        assert_unique_timestamps(&[]);
+        assert_unique_timestamps(&[1_700_000_000_000]);
+        assert_unique_timestamps(&[1_000, 2_000, 2_001, 60_000]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate timestamp 2000 at index 2 (same as index 1)")]
+    fn adjacent_duplicate_timestamp_is_reported() {
+        assert_unique_timestamps(&[1_000, 2_000, 2_000, 3_000]);
+    }
+
+    #[test]
+    fn linear_scaling_is_monotone() {
+        let scores = [7.5_f64, -2.0, 0.0, 3.25, 3.25, 100.0];
+        assert_monotone(&scores, |x| 2.0 * x + 1.0, |x| *x);
+    }
+
+    #[test]
+    #[should_panic(expected = "function is not monotone: -3 (key -3) -> 9 but 0 (key 0) -> 0")]
+    fn squaring_signed_values_is_not_monotone() {
+        assert_monotone(&[2_i32, -3, 0], |x| x * x, |x| *x);
+    }
+
+    #[test]
+    #[should_panic(expected = "key NaN of NaN at index 1 is not comparable")]
+    fn nan_key_is_rejected() {
+        assert_monotone(&[1.0_f64, f64::NAN, 2.0], |x| *x, |x| *x);
+    }
 }
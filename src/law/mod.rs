@@ -1,7 +1,13 @@
 //! Law/invariant assertion helpers.
 
 pub mod algebraic;
+pub mod cache;
+pub mod collections;
+pub mod crdt;
+pub mod cross_field;
 pub mod invariants;
+pub mod panics;
+pub mod scheduling;
 pub mod serialization;
 pub mod stateful;
 #[cfg(feature = "tokio-laws")]
@@ -0,0 +1,67 @@
+//! Panic-message assertions for negative tests.
+
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Best-effort text of a panic payload (`&str` or `String`).
+fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+/// Run `body`, returning its panic message instead of unwinding, or `None` when it returns.
+///
+/// Backs [`pbt_assert_all!`](crate::pbt_assert_all), which needs every law's outcome; it stays
+/// `pub` only because that macro expands in the caller's crate.
+#[doc(hidden)]
+pub fn capture_panic(body: impl FnOnce()) -> Option<String> {
+    catch_unwind(AssertUnwindSafe(body)).err().map(|payload| {
+        panic_message(payload.as_ref())
+            .unwrap_or("<non-string payload>")
+            .to_string()
+    })
+}
+
+/// Assert `body` panics with a message containing `msg_substring`.
+///
+/// A stricter alternative to bare `#[should_panic]`: panics when `body` returns normally, and
+/// panics quoting the original message when it does not contain `msg_substring`.
+pub fn assert_panics_with(msg_substring: &str, body: impl FnOnce()) {
+    let payload = match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(()) => panic!("expected a panic containing {msg_substring:?}, but none occurred"),
+        Err(payload) => payload,
+    };
+
+    let message = panic_message(payload.as_ref()).unwrap_or("<non-string payload>");
+    assert!(
+        message.contains(msg_substring),
+        "panic message {message:?} does not contain {msg_substring:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_panic_is_caught() {
+        assert_panics_with("out of range", || panic!("index 7 out of range"));
+        assert_panics_with("static", || std::panic::panic_any("static str payload"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "panic message \"index 7 out of range\" does not contain \"overflow\""
+    )]
+    fn non_matching_panic_is_reported() {
+        assert_panics_with("overflow", || panic!("index 7 out of range"));
+    }
+
+    #[test]
+    #[should_panic(expected = "but none occurred")]
+    fn missing_panic_is_reported() {
+        assert_panics_with("anything", || {});
+    }
+}
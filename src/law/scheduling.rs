@@ -0,0 +1,89 @@
+//! Scheduler and priority-queue ordering assertions.
+
+use std::fmt::Debug;
+
+/// Assert no item was dispatched while a strictly higher-priority item was already ready.
+///
+/// `dispatch_order` lists `(priority, ready_at)` per item in the order the scheduler dispatched
+/// them, and `dispatched_at[i]` is when item `i` was dispatched. Greater priorities win, so use
+/// `std::cmp::Reverse` for "lower number is more urgent" schemes. Item `j` dispatched after
+/// item `i` is an inversion when it outranks `i` and was ready by the time `i` was dispatched.
+/// Panics with the first inversion and both items.
+pub fn assert_priority_respected<P>(dispatch_order: &[(P, u64)], dispatched_at: &[u64])
+where
+    P: Ord + Debug,
+{
+    assert_eq!(
+        dispatch_order.len(),
+        dispatched_at.len(),
+        "dispatch_order and dispatched_at must have the same length"
+    );
+
+    for (i, ((priority, _), &at)) in dispatch_order.iter().zip(dispatched_at).enumerate() {
+        let waiting = dispatch_order[i + 1..]
+            .iter()
+            .enumerate()
+            .find(|(_, (other, ready_at))| other > priority && *ready_at <= at);
+        if let Some((offset, (other, ready_at))) = waiting {
+            panic!(
+                "priority inversion: item {} (priority {:?}) dispatched at {} while item {} (priority {:?}) was ready since {}",
+                i,
+                priority,
+                at,
+                i + 1 + offset,
+                other,
+                ready_at
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum Priority {
+        Low,
+        Normal,
+        High,
+    }
+
+    #[test]
+    fn higher_priority_first_passes() {
+        // The low item runs first because the others were not ready yet at t=0.
+        assert_priority_respected(
+            &[
+                (Priority::Low, 0),
+                (Priority::High, 1),
+                (Priority::Normal, 1),
+                (Priority::Normal, 2),
+                (Priority::Low, 0),
+            ],
+            &[0, 1, 2, 3, 4],
+        );
+        assert_priority_respected::<Priority>(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "priority inversion: item 1 (priority Normal) dispatched at 2 while item 2 (priority High) was ready since 1"
+    )]
+    fn ready_high_priority_item_left_waiting_is_reported() {
+        assert_priority_respected(
+            &[
+                (Priority::Normal, 0),
+                (Priority::Normal, 0),
+                (Priority::High, 1),
+            ],
+            &[0, 2, 3],
+        );
+    }
+
+    #[test]
+    fn reversed_numeric_priorities_treat_zero_as_most_urgent() {
+        use std::cmp::Reverse;
+
+        assert_priority_respected(&[(Reverse(0), 0), (Reverse(3), 0)], &[0, 1]);
+    }
+}
@@ -23,6 +23,116 @@ where
     assert_eq!(first, second, "JSON serialization should be deterministic");
 }
 
+/// Assert a sorted collection is still sorted after a JSON roundtrip.
+///
+/// Catches payloads whose serialized form reorders or loses the data that drives ordering (for
+/// example set-backed fields or skipped sort keys). Panics with the first out-of-order pair.
+pub fn assert_roundtrip_preserves_order<T>(sorted: &[T])
+where
+    T: Serialize + DeserializeOwned + Ord + Clone + Debug,
+{
+    assert!(
+        sorted.windows(2).all(|w| w[0] <= w[1]),
+        "input to assert_roundtrip_preserves_order must be sorted"
+    );
+
+    let json = serde_json::to_string(&sorted.to_vec()).expect("JSON serialization should succeed");
+    let decoded: Vec<T> = serde_json::from_str(&json).expect("JSON deserialization should succeed");
+    assert_eq!(
+        decoded.len(),
+        sorted.len(),
+        "JSON roundtrip should preserve element count"
+    );
+    if let Some(index) = decoded.windows(2).position(|w| w[0] > w[1]) {
+        panic!(
+            "JSON roundtrip reordered elements: index {} ({:?}) sorts after index {} ({:?})",
+            index,
+            decoded[index],
+            index + 1,
+            decoded[index + 1]
+        );
+    }
+}
+
+/// Assert `serialize` normalizes parsed text to a canonical form that parses back unchanged.
+///
+/// For text formats whose serializer is not byte-identical to its input (whitespace, signs,
+/// key order), the right invariant is: every valid sample parses to `v`, `serialize(v)` parses
+/// to `v` again, and serializing that yields the same canonical text. Panics naming the sample
+/// and the step that failed.
+pub fn assert_parse_serialize_inverse<T, E, F, G>(text_samples: &[String], parse: F, serialize: G)
+where
+    T: PartialEq + Debug,
+    E: Debug,
+    F: Fn(&str) -> Result<T, E>,
+    G: Fn(&T) -> String,
+{
+    for sample in text_samples {
+        let value =
+            parse(sample).unwrap_or_else(|err| panic!("sample {sample:?} should parse: {err:?}"));
+        let canonical = serialize(&value);
+        let reparsed = parse(&canonical).unwrap_or_else(|err| {
+            panic!("serialized form {canonical:?} of sample {sample:?} does not parse: {err:?}")
+        });
+        assert!(
+            reparsed == value,
+            "sample {sample:?} parsed to {value:?} but its serialized form {canonical:?} parses to {reparsed:?}"
+        );
+        let renormalized = serialize(&reparsed);
+        assert!(
+            renormalized == canonical,
+            "serialization of sample {sample:?} is not idempotent: {canonical:?} then {renormalized:?}"
+        );
+    }
+}
+
+/// Assert a byte codec is lossless: `dec(enc(data)) == data`.
+///
+/// Generalizes roundtrip testing to compressors, ciphers and framing codecs. Panics with the
+/// lengths involved and the first differing byte offset.
+pub fn assert_lossless_transform<Enc, Dec>(data: &[u8], enc: Enc, dec: Dec)
+where
+    Enc: Fn(&[u8]) -> Vec<u8>,
+    Dec: Fn(&[u8]) -> Vec<u8>,
+{
+    let encoded = enc(data);
+    let decoded = dec(&encoded);
+    if decoded == data {
+        return;
+    }
+    let offset = decoded
+        .iter()
+        .zip(data)
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| decoded.len().min(data.len()));
+    panic!(
+        "transform is lossy: {} input byte(s) encoded to {} and decoded to {}; first difference at offset {}",
+        data.len(),
+        encoded.len(),
+        decoded.len(),
+        offset
+    );
+}
+
+/// Assert `compressed` is at most `max_ratio` times the size of `original`.
+///
+/// Guards compressors against silently storing data raw. `original` must be non-empty.
+pub fn assert_compression_ratio(original: &[u8], compressed: &[u8], max_ratio: f64) {
+    assert!(
+        !original.is_empty(),
+        "compression ratio is undefined for empty input"
+    );
+    let ratio = compressed.len() as f64 / original.len() as f64;
+    assert!(
+        ratio <= max_ratio,
+        "compressed {} byte(s) to {} (ratio {:.3}), above the allowed {}",
+        original.len(),
+        compressed.len(),
+        ratio,
+        max_ratio
+    );
+}
+
 /// Assert bincode roundtrip identity.
 #[cfg(feature = "serialization")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serialization")))]
@@ -49,3 +159,118 @@ where
         "bincode serialization should be deterministic"
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::io::{Read, Write};
+
+    use flate2::Compression;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    struct Tagged {
+        tags: BTreeSet<String>,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    struct Prioritized {
+        #[serde(skip)]
+        weight: i32,
+        label: String,
+    }
+
+    #[test]
+    fn sorted_vec_and_set_backed_fields_keep_order() {
+        assert_roundtrip_preserves_order(&[-5_i64, 0, 3, 3, 42]);
+
+        let tagged = |tags: &[&str], name: &str| Tagged {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            name: name.to_string(),
+        };
+        let mut values = vec![
+            tagged(&["zeta", "alpha"], "b"),
+            tagged(&["alpha"], "a"),
+            tagged(&["beta", "alpha"], "c"),
+        ];
+        values.sort();
+        assert_roundtrip_preserves_order(&values);
+    }
+
+    #[test]
+    #[should_panic(expected = "JSON roundtrip reordered elements: index 0")]
+    fn skipped_sort_key_is_detected() {
+        assert_roundtrip_preserves_order(&[
+            Prioritized {
+                weight: 1,
+                label: "b".to_string(),
+            },
+            Prioritized {
+                weight: 2,
+                label: "a".to_string(),
+            },
+        ]);
+    }
+
+    fn samples(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn number_parser_normalizes_whitespace_and_sign() {
+        assert_parse_serialize_inverse(
+            &samples(&[" 42 ", "\t-7\n", "+5", "0"]),
+            |s| s.trim().parse::<i64>(),
+            |n| n.to_string(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "sample \"1.25\" parsed to 1.25 but its serialized form \"1.2\"")]
+    fn lossy_serializer_is_detected() {
+        assert_parse_serialize_inverse(
+            &samples(&["2", "1.25"]),
+            |s| s.trim().parse::<f64>(),
+            |x| format!("{x:.1}"),
+        );
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).expect("gzip write should succeed");
+        encoder.finish().expect("gzip finish should succeed")
+    }
+
+    fn gunzip(data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        GzDecoder::new(data)
+            .read_to_end(&mut out)
+            .expect("gunzip should succeed");
+        out
+    }
+
+    #[test]
+    fn gzip_roundtrip_is_lossless_and_compresses_repetitive_input() {
+        let log = b"mutant m1 killed\n".repeat(200);
+        assert_lossless_transform(&log, gzip, gunzip);
+        assert_lossless_transform(&[], gzip, gunzip);
+        assert_compression_ratio(&log, &gzip(&log), 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "first difference at offset 3")]
+    fn lossy_codec_is_detected() {
+        assert_lossless_transform(b"abc\xff", |d| d.to_vec(), |d| d[..3].to_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "ratio 1.000), above the allowed 0.5")]
+    fn raw_storage_fails_compression_ratio() {
+        assert_compression_ratio(b"abcdefgh", b"abcdefgh", 0.5);
+    }
+}
@@ -130,6 +130,34 @@ where
     }
 }
 
+/// Assert the `p`-th percentile (nearest rank, `0 < p <= 100`) of `samples` is within `budget`.
+///
+/// Expresses SLOs such as "p99 < 200ms" directly against recorded latencies. Panics reporting
+/// the computed percentile and sample count when the budget is exceeded.
+pub fn assert_latency_slo(samples: &[Duration], p: f64, budget: Duration) {
+    assert!(
+        p > 0.0 && p <= 100.0,
+        "percentile must be in (0, 100], got {p}"
+    );
+    assert!(
+        !samples.is_empty(),
+        "latency SLO check needs at least one sample"
+    );
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    let observed = sorted[rank.clamp(1, sorted.len()) - 1];
+    assert!(
+        observed <= budget,
+        "p{} latency {:?} exceeds budget {:?} over {} sample(s)",
+        p,
+        observed,
+        budget,
+        sorted.len()
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +206,24 @@ mod tests {
         assert_backoff_bounds(&probe, Duration::from_millis(1), Duration::from_millis(20)).await;
         assert_interval_no_drift(&probe, Duration::from_millis(5)).await;
     }
+
+    fn latencies(slow: usize) -> Vec<Duration> {
+        let mut samples: Vec<Duration> = (0..100).map(|i| Duration::from_millis(20 + i)).collect();
+        for sample in samples.iter_mut().take(slow) {
+            *sample = Duration::from_millis(450);
+        }
+        samples
+    }
+
+    #[test]
+    fn p99_within_budget_passes() {
+        // One outlier in 100 samples is tolerated by a p99 SLO.
+        assert_latency_slo(&latencies(1), 99.0, Duration::from_millis(200));
+    }
+
+    #[test]
+    #[should_panic(expected = "p99 latency 450ms exceeds budget 200ms over 100 sample(s)")]
+    fn p99_over_budget_reports_percentile() {
+        assert_latency_slo(&latencies(2), 99.0, Duration::from_millis(200));
+    }
 }
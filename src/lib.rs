@@ -3,6 +3,13 @@
 //! `kitchensink-testing` is a domain-agnostic property-based testing toolkit organized around:
 //! - `generators`: reusable strategy primitives
 //! - `law`: reusable law/invariant assertions
+//! - `bench`: deterministic strategy samples for benchmark fixtures (`sample_n`)
+//! - `config`: team-wide proptest runner defaults (`pbt_config`)
+//! - `corpus`: shareable corpora of previously failing inputs mixed into strategies
+//! - `coverage`: strategy coverage/distribution assertions
+//! - `golden`: snapshot/golden-file assertions for rendered output
+//! - `runner`: macro-free property checks (`check_law`)
+//! - `seed`: seeded sampling and strategy determinism checks
 //! - `law::tokio`: trait-driven Tokio concurrency laws (feature `tokio-laws`)
 //! - `mutation`: resumable mutation orchestration (feature `mutation`)
 //!
@@ -12,10 +19,17 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
 
+pub mod bench;
 pub mod chaos;
+pub mod config;
+pub mod corpus;
+pub mod coverage;
 pub mod generators;
+pub mod golden;
 pub mod law;
 pub mod prelude;
+pub mod runner;
+pub mod seed;
 
 #[cfg(feature = "mutation")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mutation")))]
@@ -2,8 +2,69 @@
 
 use std::path::PathBuf;
 
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::events::{MutationOutcome, MutationType};
+
+/// Which mutant outcomes get stdout/stderr artifacts written to disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactPolicy {
+    /// Write artifacts for every mutant with output, and always for errors.
+    #[default]
+    All,
+    /// Skip artifacts for killed mutants.
+    FailuresOnly,
+    /// Only write artifacts for survived and error mutants.
+    SurvivorsAndErrors,
+    /// Never write artifacts.
+    None,
+}
+
+impl ArtifactPolicy {
+    /// True if artifacts should be written for a mutant with this outcome.
+    pub fn allows(&self, outcome: &MutationOutcome) -> bool {
+        match self {
+            Self::All => true,
+            Self::FailuresOnly => !matches!(outcome, MutationOutcome::Killed),
+            Self::SurvivorsAndErrors => matches!(
+                outcome,
+                MutationOutcome::Survived | MutationOutcome::Error { .. }
+            ),
+            Self::None => false,
+        }
+    }
+}
+
+/// Ordered label-pattern rules consulted before the built-in mutation-type heuristics.
+///
+/// The first rule whose pattern matches a mutant label decides its type. Rules compare equal
+/// when their pattern sources and types match.
+#[derive(Debug, Clone, Default)]
+pub struct MutationTypeRules(pub Vec<(Regex, MutationType)>);
+
+impl MutationTypeRules {
+    /// Type assigned by the first matching rule, if any.
+    pub fn classify(&self, label: &str) -> Option<MutationType> {
+        self.0
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(label))
+            .map(|(_, mutation_type)| *mutation_type)
+    }
+}
+
+impl PartialEq for MutationTypeRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|((a, a_type), (b, b_type))| a.as_str() == b.as_str() && a_type == b_type)
+    }
+}
+
 /// Configuration for a mutation run.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MutationConfig {
@@ -15,6 +76,27 @@ pub struct MutationConfig {
     pub filter: Option<String>,
     /// Optional per-mutant timeout hint in seconds.
     pub timeout_secs: Option<u64>,
+    /// Which outcomes get stdout/stderr artifacts persisted.
+    #[serde(default)]
+    pub artifact_policy: ArtifactPolicy,
+    /// Workspace members passed to cargo-mutants as `--package`; empty mutates every member.
+    #[serde(default)]
+    pub packages: Vec<String>,
+    /// Extra arguments appended to every `cargo mutants` invocation (e.g. `--features x`).
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// Arguments passed through to `cargo test` after a `--` separator.
+    #[serde(default)]
+    pub test_args: Vec<String>,
+    /// Return the latest completed run unchanged when it has no survivors or errors.
+    #[serde(default)]
+    pub reuse_clean_runs: bool,
+    /// Base URL for source links in reports, e.g. `https://github.com/owner/repo/blob`.
+    #[serde(default)]
+    pub source_base_url: Option<String>,
+    /// User classification rules applied to discovered mutant labels; not persisted.
+    #[serde(skip)]
+    pub type_rules: MutationTypeRules,
 }
 
 impl Default for MutationConfig {
@@ -29,6 +111,13 @@ impl Default for MutationConfig {
             run_root,
             filter: None,
             timeout_secs: None,
+            artifact_policy: ArtifactPolicy::default(),
+            packages: Vec::new(),
+            extra_args: Vec::new(),
+            test_args: Vec::new(),
+            reuse_clean_runs: false,
+            source_base_url: None,
+            type_rules: MutationTypeRules::default(),
         }
     }
 }
@@ -57,6 +146,64 @@ impl MutationConfig {
         self.timeout_secs = Some(timeout_secs);
         self
     }
+
+    /// Set artifact-writing policy.
+    pub fn with_artifact_policy(mut self, artifact_policy: ArtifactPolicy) -> Self {
+        self.artifact_policy = artifact_policy;
+        self
+    }
+
+    /// Add a workspace member to mutate.
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.packages.push(package.into());
+        self
+    }
+
+    /// Add several workspace members to mutate.
+    pub fn with_packages<I, S>(mut self, packages: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.packages.extend(packages.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set extra `cargo mutants` arguments.
+    pub fn with_extra_args(mut self, extra_args: Vec<String>) -> Self {
+        self.extra_args = extra_args;
+        self
+    }
+
+    /// Set arguments forwarded to `cargo test`.
+    pub fn with_test_args(mut self, test_args: Vec<String>) -> Self {
+        self.test_args = test_args;
+        self
+    }
+
+    /// Short-circuit `run_new` when the latest compatible run completed clean.
+    pub fn with_reuse_clean_runs(mut self, reuse_clean_runs: bool) -> Self {
+        self.reuse_clean_runs = reuse_clean_runs;
+        self
+    }
+
+    /// Set the base URL reports use to link mutants to `{base}/{git_commit}/{file}#L{line}`.
+    pub fn with_source_base_url(mut self, source_base_url: impl Into<String>) -> Self {
+        self.source_base_url = Some(source_base_url.into());
+        self
+    }
+
+    /// Set ordered `(pattern, type)` rules that classify mutant labels before the built-in
+    /// heuristics of [`parse_mutation_type`](super::events::parse_mutation_type).
+    pub fn with_type_rules(mut self, type_rules: Vec<(Regex, MutationType)>) -> Self {
+        self.type_rules = MutationTypeRules(type_rules);
+        self
+    }
+
+    /// Mutation type for `label` from the first matching user rule, if any.
+    pub fn classify_mutation_type(&self, label: &str) -> Option<MutationType> {
+        self.type_rules.classify(label)
+    }
 }
 
 #[cfg(test)]
@@ -76,11 +223,54 @@ mod tests {
             .with_project_dir("/tmp/project-a")
             .with_run_root("/tmp/runs-a")
             .with_filter("abc")
-            .with_timeout_secs(42);
+            .with_timeout_secs(42)
+            .with_artifact_policy(ArtifactPolicy::FailuresOnly)
+            .with_package("core")
+            .with_packages(["cli", "server"])
+            .with_extra_args(vec!["--features".to_string(), "fast".to_string()])
+            .with_test_args(vec!["--test-threads=1".to_string()])
+            .with_reuse_clean_runs(true)
+            .with_source_base_url("https://github.com/o/r/blob");
 
         assert_eq!(cfg.project_dir, PathBuf::from("/tmp/project-a"));
         assert_eq!(cfg.run_root, PathBuf::from("/tmp/runs-a"));
         assert_eq!(cfg.filter.as_deref(), Some("abc"));
         assert_eq!(cfg.timeout_secs, Some(42));
+        assert_eq!(cfg.artifact_policy, ArtifactPolicy::FailuresOnly);
+        assert_eq!(cfg.packages, vec!["core", "cli", "server"]);
+        assert_eq!(cfg.extra_args, vec!["--features", "fast"]);
+        assert_eq!(cfg.test_args, vec!["--test-threads=1"]);
+        assert!(cfg.reuse_clean_runs);
+        assert_eq!(
+            cfg.source_base_url.as_deref(),
+            Some("https://github.com/o/r/blob")
+        );
+    }
+
+    #[test]
+    fn user_type_rule_overrides_builtin_classification_of_ambiguous_label() {
+        use crate::mutation::events::parse_mutation_type;
+
+        let label = "src/lib.rs:12:5: replace Vec<u8>::len -> usize with 0";
+        assert_eq!(parse_mutation_type(label), MutationType::Comparison);
+
+        let cfg = MutationConfig::default().with_type_rules(vec![
+            (
+                Regex::new(r"replace .* -> \S+ with ").expect("valid regex"),
+                MutationType::ReturnValue,
+            ),
+            (
+                Regex::new(r"replace .*").expect("valid regex"),
+                MutationType::Arithmetic,
+            ),
+        ]);
+        assert_eq!(
+            cfg.classify_mutation_type(label),
+            Some(MutationType::ReturnValue)
+        );
+        assert_eq!(
+            cfg.classify_mutation_type("src/lib.rs:3:1: delete ! in is_ready"),
+            None
+        );
     }
 }
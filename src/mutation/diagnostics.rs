@@ -0,0 +1,214 @@
+//! Run-integrity self-checks over persisted mutation runs.
+
+use std::path::Path;
+
+use proptest::prelude::RngCore;
+
+use super::events::MutationEvent;
+use super::state::{MutationStatus, RunSnapshot, replay_event_list};
+use crate::seed::seeded_runner;
+
+/// Assert every recorded stdout/stderr artifact of a non-skipped mutant exists under `run_dir`.
+///
+/// Panics listing every `(mutant id, artifact path)` whose file is missing on disk.
+pub fn assert_artifacts_present(snapshot: &RunSnapshot, run_dir: &Path) {
+    let mut missing = Vec::new();
+
+    for state in snapshot.mutants.values() {
+        if matches!(state.status, MutationStatus::Skipped) {
+            continue;
+        }
+        for path in [&state.stdout_artifact_path, &state.stderr_artifact_path]
+            .into_iter()
+            .flatten()
+        {
+            if !run_dir.join(path).is_file() {
+                missing.push(format!("{}: {}", state.spec.id, path));
+            }
+        }
+    }
+
+    assert!(
+        missing.is_empty(),
+        "run {} references {} missing artifact(s) under {}:\n  {}",
+        snapshot.run_id,
+        missing.len(),
+        run_dir.display(),
+        missing.join("\n  ")
+    );
+}
+
+/// Number of seeded random interleavings checked by [`assert_replay_order_independent`].
+pub const REPLAY_INTERLEAVINGS: usize = 32;
+
+/// Assert replay derives the same snapshot when causally independent events are reordered.
+///
+/// Run-level markers keep their positions and each mutant's own events keep their relative order
+/// (`MutantDiscovered` before `MutantStarted` before `MutantFinished`); only events belonging to
+/// different mutants are interleaved differently. Three canonical reorderings (grouped by mutant,
+/// reverse mutant order, round-robin) are checked, followed by [`REPLAY_INTERLEAVINGS`] random
+/// interleavings drawn from `seed`. Panics naming the first reordering whose snapshot differs.
+pub fn assert_replay_order_independent(events: &[MutationEvent], seed: u64) {
+    let baseline = replay_event_list(events);
+
+    let mut slots = Vec::new();
+    let mut queues: Vec<(&str, Vec<&MutationEvent>)> = Vec::new();
+    for (index, event) in events.iter().enumerate() {
+        let Some(id) = mutant_id(event) else {
+            continue;
+        };
+        slots.push(index);
+        match queues.iter_mut().find(|(queue_id, _)| *queue_id == id) {
+            Some((_, queue)) => queue.push(event),
+            None => queues.push((id, vec![event])),
+        }
+    }
+
+    let grouped: Vec<&MutationEvent> = queues.iter().flat_map(|(_, q)| q.clone()).collect();
+    let reversed: Vec<&MutationEvent> = queues.iter().rev().flat_map(|(_, q)| q.clone()).collect();
+    let longest = queues.iter().map(|(_, q)| q.len()).max().unwrap_or(0);
+    let round_robin: Vec<&MutationEvent> = (0..longest)
+        .flat_map(|round| {
+            queues
+                .iter()
+                .filter_map(move |(_, q)| q.get(round).copied())
+        })
+        .collect();
+
+    let mut orders = vec![
+        ("grouped by mutant".to_string(), grouped),
+        ("reverse mutant order".to_string(), reversed),
+        ("round-robin".to_string(), round_robin),
+    ];
+    let mut runner = seeded_runner(seed);
+    for round in 0..REPLAY_INTERLEAVINGS {
+        let mut cursors = vec![0; queues.len()];
+        let mut order = Vec::with_capacity(slots.len());
+        while order.len() < slots.len() {
+            let open: Vec<usize> = (0..queues.len())
+                .filter(|&q| cursors[q] < queues[q].1.len())
+                .collect();
+            let q = open[runner.rng().next_u64() as usize % open.len()];
+            order.push(queues[q].1[cursors[q]]);
+            cursors[q] += 1;
+        }
+        orders.push((format!("random interleaving {round} of seed {seed}"), order));
+    }
+
+    for (name, order) in orders {
+        let mut reordered = events.to_vec();
+        for (&slot, event) in slots.iter().zip(order) {
+            reordered[slot] = event.clone();
+        }
+        let snapshot = replay_event_list(&reordered);
+        assert!(
+            snapshot == baseline,
+            "replay is order dependent: {name} reordering of {} event(s) changed the snapshot\n  original:  {:?}\n  reordered: {:?}",
+            events.len(),
+            baseline,
+            snapshot
+        );
+    }
+}
+
+fn mutant_id(event: &MutationEvent) -> Option<&str> {
+    match event {
+        MutationEvent::MutantDiscovered { mutant, .. } => Some(&mutant.id),
+        MutationEvent::MutantStarted { mutant_id, .. }
+        | MutationEvent::MutantFinished { mutant_id, .. } => Some(mutant_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use proptest::prelude::*;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::generators::mutation::mutation_event_log_strategy;
+    use crate::mutation::events::{MutantSpec, MutationType};
+    use crate::mutation::state::{MutantState, RunInfo};
+
+    fn state_with_artifact(id: &str, status: MutationStatus, stdout: &str) -> MutantState {
+        MutantState {
+            spec: MutantSpec {
+                id: id.to_string(),
+                label: "label".to_string(),
+                selector: "selector".to_string(),
+                source_file: String::new(),
+                source_line: 0,
+                function: None,
+                mutation_type: MutationType::Unknown,
+                original_code: String::new(),
+                mutated_code: String::new(),
+            },
+            status,
+            started_at_ms: None,
+            finished_at_ms: None,
+            duration_ms: None,
+            exit_code: None,
+            stdout_artifact_path: Some(stdout.to_string()),
+            stderr_artifact_path: None,
+            last_error: None,
+            tests_run: Vec::new(),
+            tests_failed: Vec::new(),
+            stdout_preview: None,
+            stderr_preview: None,
+            previous_status: None,
+        }
+    }
+
+    fn snapshot(states: Vec<MutantState>) -> RunSnapshot {
+        RunSnapshot {
+            run_id: "run-artifacts".to_string(),
+            mutants: states
+                .into_iter()
+                .map(|state| (state.spec.id.clone(), state))
+                .collect::<BTreeMap<_, _>>(),
+            malformed_lines: 0,
+            interrupted: false,
+            completed: true,
+            info: RunInfo::default(),
+            started_at_ms: None,
+            ended_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn present_artifacts_pass() {
+        let tmp = tempdir().expect("tempdir should be created");
+        std::fs::create_dir_all(tmp.path().join("artifacts")).expect("artifacts dir");
+        std::fs::write(tmp.path().join("artifacts/m1.stdout.log"), "ok").expect("artifact");
+
+        let snapshot = snapshot(vec![
+            state_with_artifact("m1", MutationStatus::Survived, "artifacts/m1.stdout.log"),
+            state_with_artifact("m2", MutationStatus::Skipped, "artifacts/m2.stdout.log"),
+        ]);
+        assert_artifacts_present(&snapshot, tmp.path());
+    }
+
+    #[test]
+    #[should_panic(expected = "m1: artifacts/m1.stdout.log")]
+    fn missing_artifact_is_reported() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let snapshot = snapshot(vec![state_with_artifact(
+            "m1",
+            MutationStatus::Survived,
+            "artifacts/m1.stdout.log",
+        )]);
+        assert_artifacts_present(&snapshot, tmp.path());
+    }
+
+    proptest! {
+        #[test]
+        fn replay_ignores_interleaving_of_independent_mutants(
+            events in mutation_event_log_strategy(6),
+            seed in any::<u64>()
+        ) {
+            assert_replay_order_independent(&events, seed);
+        }
+    }
+}
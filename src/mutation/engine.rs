@@ -1,6 +1,7 @@
 //! Mutation engine abstraction and cargo-mutants adapter.
 
-use std::process::Command;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
 
 use thiserror::Error;
 
@@ -21,6 +22,9 @@ pub enum MutationEngineError {
     /// Engine lacks required capability for resumable single-mutant execution.
     #[error("unsupported cargo-mutants capability: {0}")]
     Unsupported(String),
+    /// No outcome is available for the requested mutant.
+    #[error("no recorded outcome for mutant {0}")]
+    UnknownMutant(String),
     /// IO failure.
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
@@ -41,12 +45,49 @@ pub struct MutantExecutionResult {
 
 /// Mutation engine contract.
 pub trait MutationEngine {
+    /// Stable engine identity recorded in run snapshots for resume compatibility.
+    ///
+    /// Engines that do not override this share the `custom` identity.
+    fn name(&self) -> &str {
+        "custom"
+    }
+
     /// Discover available mutants.
     fn discover_mutants(
         &self,
         config: &MutationConfig,
     ) -> Result<Vec<MutantSpec>, MutationEngineError>;
 
+    /// Discover mutants, handing each one to `on_mutant` as soon as it is known.
+    ///
+    /// Returns the number of mutants delivered. The default delegates to
+    /// [`MutationEngine::discover_mutants`]; engines backed by a long-running listing process
+    /// override it so callers can start work before discovery completes.
+    ///
+    /// Callers only execute mutants from inside `on_mutant` when
+    /// [`MutationEngine::supports_concurrent_discovery`] is true. Otherwise the listing is
+    /// finished before the first execution: an engine that mutates the source tree in place,
+    /// like `cargo mutants --in-place`, would otherwise edit files the listing is still reading.
+    fn discover_mutants_streaming(
+        &self,
+        config: &MutationConfig,
+        on_mutant: &mut dyn FnMut(MutantSpec),
+    ) -> Result<usize, MutationEngineError> {
+        let mutants = self.discover_mutants(config)?;
+        let count = mutants.len();
+        mutants.into_iter().for_each(on_mutant);
+        Ok(count)
+    }
+
+    /// Whether mutants may execute while [`MutationEngine::discover_mutants_streaming`] is
+    /// still listing.
+    ///
+    /// Defaults to `false`. Only engines whose execution leaves the listed source tree untouched
+    /// should return `true`.
+    fn supports_concurrent_discovery(&self) -> bool {
+        false
+    }
+
     /// Execute one mutant and return its outcome.
     fn execute_mutant(
         &self,
@@ -55,6 +96,23 @@ pub trait MutationEngine {
     ) -> Result<MutantExecutionResult, MutationEngineError>;
 }
 
+/// FNV-1a 64-bit hash of `input`, used to derive persisted mutant ids.
+///
+/// The output is part of the on-disk run format: ids written by earlier runs must keep matching,
+/// so the algorithm is frozen and pinned by golden vectors. Code that persists its own keys may
+/// rely on it for the same reason.
+pub fn stable_hash(input: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for b in input.as_bytes() {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
 /// Adapter for `cargo-mutants` CLI.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct CargoMutantsEngine;
@@ -88,19 +146,6 @@ impl CargoMutantsEngine {
         Ok(text.contains("--mutant"))
     }
 
-    fn stable_hash(input: &str) -> u64 {
-        // FNV-1a 64-bit.
-        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
-        const PRIME: u64 = 0x100000001b3;
-
-        let mut hash = OFFSET_BASIS;
-        for b in input.as_bytes() {
-            hash ^= *b as u64;
-            hash = hash.wrapping_mul(PRIME);
-        }
-        hash
-    }
-
     fn classify_outcome(status: std::process::ExitStatus, text: &str) -> MutationOutcome {
         let lower = text.to_ascii_lowercase();
 
@@ -134,20 +179,136 @@ impl CargoMutantsEngine {
         }
     }
 
-    fn parse_label(label: &str) -> (String, u32, String) {
-        // cargo-mutants label format: "src/lib.rs:42:5: replace + with *"
+    fn parse_list_line(idx: usize, raw: &str) -> Option<MutantSpec> {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with("Found ") || line.starts_with("Listing ") {
+            return None;
+        }
+
+        let id = format!("m{:04x}", stable_hash(&format!("{idx}:{line}")));
+        let (source_file, source_line, mutation_desc, function) = Self::parse_label(line);
+        Some(MutantSpec {
+            id,
+            label: line.to_string(),
+            selector: line.to_string(),
+            source_file,
+            source_line,
+            function,
+            mutation_type: parse_mutation_type(line),
+            original_code: String::new(),
+            mutated_code: mutation_desc,
+        })
+    }
+
+    /// Run a `--list` command and parse its stdout line by line as it is produced.
+    ///
+    /// Mutants parsed before a failing exit status have already been delivered.
+    fn stream_list_output(
+        mut cmd: Command,
+        on_mutant: &mut dyn FnMut(MutantSpec),
+    ) -> Result<usize, MutationEngineError> {
+        let mut child = match cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn() {
+            Ok(child) => child,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(MutationEngineError::MissingCargoMutants);
+            }
+            Err(err) => return Err(MutationEngineError::Io(err)),
+        };
+
+        // Drain stderr concurrently so a chatty listing cannot block on a full pipe.
+        let mut stderr = child.stderr.take().expect("stderr should be piped");
+        let stderr_reader = std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = stderr.read_to_end(&mut buf);
+            buf
+        });
+
+        let stdout = child.stdout.take().expect("stdout should be piped");
+        let mut count = 0;
+        for (idx, raw) in BufReader::new(stdout).split(b'\n').enumerate() {
+            let raw = match raw {
+                Ok(raw) => raw,
+                Err(err) => {
+                    // Reap the listing and its stderr drain before bailing out.
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    let _ = stderr_reader.join();
+                    return Err(MutationEngineError::Io(err));
+                }
+            };
+            if let Some(mutant) = Self::parse_list_line(idx, &String::from_utf8_lossy(&raw)) {
+                on_mutant(mutant);
+                count += 1;
+            }
+        }
+
+        let status = child.wait();
+        let stderr = stderr_reader.join().unwrap_or_default();
+        let status = status?;
+        if !status.success() {
+            if Self::command_output_missing_command(&stderr) {
+                return Err(MutationEngineError::MissingCargoMutants);
+            }
+            return Err(MutationEngineError::CommandFailed(
+                String::from_utf8_lossy(&stderr).to_string(),
+            ));
+        }
+
+        if count == 0 {
+            return Err(MutationEngineError::CommandFailed(
+                "`cargo mutants --list` returned no mutants".to_string(),
+            ));
+        }
+
+        Ok(count)
+    }
+
+    fn parse_label(label: &str) -> (String, u32, String, Option<String>) {
+        // cargo-mutants label format: "src/lib.rs:42:5: replace + with * in Calc::add"
         let parts: Vec<&str> = label.splitn(4, ':').collect();
         if parts.len() >= 4 {
             let file = parts[0].to_string();
             let line = parts[1].parse().unwrap_or(0);
             let desc = parts[3].trim().to_string();
-            (file, line, desc)
+            let function = Self::parse_function(&desc);
+            (file, line, desc, function)
         } else if parts.len() >= 2 {
             let file = parts[0].to_string();
             let line = parts[1].parse().unwrap_or(0);
-            (file, line, label.to_string())
+            (file, line, label.to_string(), None)
         } else {
-            (String::new(), 0, label.to_string())
+            (String::new(), 0, label.to_string(), None)
+        }
+    }
+
+    fn parse_function(desc: &str) -> Option<String> {
+        // Operator mutations name their context as a trailing "in <function>".
+        if let Some((_, function)) = desc.rsplit_once(" in ") {
+            return Some(function.trim().to_string()).filter(|f| !f.is_empty());
+        }
+
+        // Whole-body replacements name the function itself:
+        // "replace Calc::add -> i32 with 0", "replace Calc::reset with ()".
+        let target = desc.strip_prefix("replace ")?;
+        let function = match target.split_once(" -> ") {
+            Some((function, _)) => function,
+            None => target
+                .strip_suffix(" with ()")
+                .or_else(|| target.strip_suffix(" with Default::default()"))?,
+        };
+        Some(function.trim().to_string()).filter(|f| !f.is_empty())
+    }
+
+    fn push_selection_args(cmd: &mut Command, config: &MutationConfig) {
+        for package in &config.packages {
+            cmd.arg("--package").arg(package);
+        }
+        cmd.args(&config.extra_args);
+    }
+
+    fn push_test_args(cmd: &mut Command, config: &MutationConfig) {
+        if !config.test_args.is_empty() {
+            cmd.arg("--").args(&config.test_args);
         }
     }
 
@@ -173,69 +334,32 @@ impl CargoMutantsEngine {
 }
 
 impl MutationEngine for CargoMutantsEngine {
+    fn name(&self) -> &str {
+        "cargo-mutants"
+    }
+
     fn discover_mutants(
         &self,
         config: &MutationConfig,
     ) -> Result<Vec<MutantSpec>, MutationEngineError> {
-        let output = Command::new("cargo")
-            .arg("mutants")
-            .arg("--list")
-            .current_dir(&config.project_dir)
-            .output();
-
-        let output = match output {
-            Ok(out) => out,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                return Err(MutationEngineError::MissingCargoMutants);
-            }
-            Err(err) => return Err(MutationEngineError::Io(err)),
-        };
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            if Self::command_output_missing_command(&output.stderr) {
-                return Err(MutationEngineError::MissingCargoMutants);
-            }
-            return Err(MutationEngineError::CommandFailed(stderr));
-        }
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
         let mut mutants = Vec::new();
-
-        for (idx, raw) in stdout.lines().enumerate() {
-            let line = raw.trim();
-            if line.is_empty() {
-                continue;
-            }
-            if line.starts_with("Found ") || line.starts_with("Listing ") {
-                continue;
-            }
-
-            let id = format!("m{:04x}", Self::stable_hash(&format!("{idx}:{line}")));
-            let (source_file, source_line, mutation_desc) = Self::parse_label(line);
-            let mutation_type = parse_mutation_type(line);
-
-            mutants.push(MutantSpec {
-                id,
-                label: line.to_string(),
-                selector: line.to_string(),
-                source_file,
-                source_line,
-                mutation_type,
-                original_code: String::new(),
-                mutated_code: mutation_desc,
-            });
-        }
-
-        if mutants.is_empty() {
-            return Err(MutationEngineError::CommandFailed(
-                "`cargo mutants --list` returned no mutants".to_string(),
-            ));
-        }
-
+        self.discover_mutants_streaming(config, &mut |mutant| mutants.push(mutant))?;
         Ok(mutants)
     }
 
+    fn discover_mutants_streaming(
+        &self,
+        config: &MutationConfig,
+        on_mutant: &mut dyn FnMut(MutantSpec),
+    ) -> Result<usize, MutationEngineError> {
+        let mut cmd = Command::new("cargo");
+        cmd.arg("mutants")
+            .arg("--list")
+            .current_dir(&config.project_dir);
+        Self::push_selection_args(&mut cmd, config);
+        Self::stream_list_output(cmd, on_mutant)
+    }
+
     fn execute_mutant(
         &self,
         config: &MutationConfig,
@@ -257,6 +381,8 @@ impl MutationEngine for CargoMutantsEngine {
         if let Some(timeout_secs) = config.timeout_secs {
             cmd.arg("--timeout").arg(timeout_secs.to_string());
         }
+        Self::push_selection_args(&mut cmd, config);
+        Self::push_test_args(&mut cmd, config);
 
         let output = cmd.output()?;
         if !output.status.success() && Self::command_output_missing_command(&output.stderr) {
@@ -273,6 +399,61 @@ impl MutationEngine for CargoMutantsEngine {
     }
 }
 
+/// Engine that discovers through `inner` but never executes: every mutant comes back `Skipped`.
+///
+/// Validates discovery, filtering, and report plumbing on a real project without paying for
+/// test runs. Runs record the `dry-run` engine name, so they are never resumed by real runs.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DryRunEngine<E = CargoMutantsEngine> {
+    inner: E,
+}
+
+impl<E: MutationEngine> DryRunEngine<E> {
+    /// Wrap `inner`, whose discovery is used as-is.
+    pub fn new(inner: E) -> Self {
+        Self { inner }
+    }
+}
+
+impl<E: MutationEngine> MutationEngine for DryRunEngine<E> {
+    fn name(&self) -> &str {
+        "dry-run"
+    }
+
+    fn discover_mutants(
+        &self,
+        config: &MutationConfig,
+    ) -> Result<Vec<MutantSpec>, MutationEngineError> {
+        self.inner.discover_mutants(config)
+    }
+
+    fn discover_mutants_streaming(
+        &self,
+        config: &MutationConfig,
+        on_mutant: &mut dyn FnMut(MutantSpec),
+    ) -> Result<usize, MutationEngineError> {
+        self.inner.discover_mutants_streaming(config, on_mutant)
+    }
+
+    fn supports_concurrent_discovery(&self) -> bool {
+        // Dry runs never touch the tree, so execution cannot race the inner listing.
+        true
+    }
+
+    fn execute_mutant(
+        &self,
+        _config: &MutationConfig,
+        _mutant: &MutantSpec,
+    ) -> Result<MutantExecutionResult, MutationEngineError> {
+        Ok(MutantExecutionResult {
+            outcome: MutationOutcome::Skipped,
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::process::Command;
@@ -281,13 +462,30 @@ mod tests {
 
     #[test]
     fn stable_hash_is_deterministic() {
-        let a = CargoMutantsEngine::stable_hash("same-input");
-        let b = CargoMutantsEngine::stable_hash("same-input");
-        let c = CargoMutantsEngine::stable_hash("other-input");
+        let a = stable_hash("same-input");
+        let b = stable_hash("same-input");
+        let c = stable_hash("other-input");
         assert_eq!(a, b);
         assert_ne!(a, c);
     }
 
+    #[test]
+    fn stable_hash_matches_golden_vectors() {
+        // Persisted mutant ids depend on these exact values; never update them to fit a change.
+        let golden = [
+            ("", 0xcbf29ce484222325),
+            ("a", 0xaf63dc4c8601ec8c),
+            ("foobar", 0x85944171f73967e8),
+            (
+                "0:src/lib.rs:10:5: replace + with - in add",
+                0x68dd0e995e37e8b8,
+            ),
+        ];
+        for (input, expected) in golden {
+            assert_eq!(stable_hash(input), expected, "hash of {input:?} changed");
+        }
+    }
+
     #[test]
     fn classify_outcome_uses_keywords() {
         let ok_status = Command::new("sh")
@@ -345,6 +543,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_label_captures_function_context() {
+        let (file, line, desc, function) =
+            CargoMutantsEngine::parse_label("src/calc.rs:42:11: replace + with - in Calc::add");
+        assert_eq!(file, "src/calc.rs");
+        assert_eq!(line, 42);
+        assert_eq!(desc, "replace + with - in Calc::add");
+        assert_eq!(function.as_deref(), Some("Calc::add"));
+
+        let cases = [
+            (
+                "src/fmt.rs:9:9: replace == with != in <impl Display for Money>::fmt",
+                Some("<impl Display for Money>::fmt"),
+            ),
+            (
+                "src/calc.rs:10:5: replace Calc::total -> i64 with 0",
+                Some("Calc::total"),
+            ),
+            (
+                "src/calc.rs:20:5: replace Calc::reset with ()",
+                Some("Calc::reset"),
+            ),
+            (
+                "src/calc.rs:30:5: replace Calc::new with Default::default()",
+                Some("Calc::new"),
+            ),
+            ("src/calc.rs:40:5: delete ! in is_empty", Some("is_empty")),
+            ("src/calc.rs:50:7: replace + with -", None),
+            ("unparseable label", None),
+        ];
+        for (label, expected) in cases {
+            assert_eq!(
+                CargoMutantsEngine::parse_label(label).3.as_deref(),
+                expected,
+                "label: {label}"
+            );
+        }
+    }
+
+    #[test]
+    fn streaming_discovery_delivers_listed_mutants_in_order() {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(
+            "echo 'Found 3 mutants'; \
+             for i in 1 2 3; do echo \"src/lib.rs:$i:5: replace + with -\"; done",
+        );
+
+        let mut labels = Vec::new();
+        let count = CargoMutantsEngine::stream_list_output(cmd, &mut |mutant| {
+            labels.push(mutant.label);
+        })
+        .expect("streaming discovery should succeed");
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            labels,
+            [
+                "src/lib.rs:1:5: replace + with -",
+                "src/lib.rs:2:5: replace + with -",
+                "src/lib.rs:3:5: replace + with -"
+            ]
+        );
+    }
+
+    #[test]
+    fn streaming_discovery_ids_match_line_positions() {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c")
+            .arg("printf 'Listing mutants\\n\\nsrc/a.rs:1:1: replace + with -\\n'");
+        let mut ids = Vec::new();
+        CargoMutantsEngine::stream_list_output(cmd, &mut |mutant| ids.push(mutant.id))
+            .expect("streaming discovery should succeed");
+        assert_eq!(
+            ids,
+            vec![format!(
+                "m{:04x}",
+                stable_hash("2:src/a.rs:1:1: replace + with -")
+            )]
+        );
+    }
+
+    #[test]
+    fn selection_and_test_args_are_separated_by_double_dash() {
+        let config = MutationConfig::default()
+            .with_package("core")
+            .with_extra_args(vec!["--features".to_string(), "fast".to_string()])
+            .with_test_args(vec!["--test-threads=1".to_string()]);
+        let mut cmd = Command::new("cargo");
+        cmd.arg("mutants");
+        CargoMutantsEngine::push_selection_args(&mut cmd, &config);
+        CargoMutantsEngine::push_test_args(&mut cmd, &config);
+
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "mutants",
+                "--package",
+                "core",
+                "--features",
+                "fast",
+                "--",
+                "--test-threads=1"
+            ]
+        );
+
+        let mut bare = Command::new("cargo");
+        CargoMutantsEngine::push_test_args(&mut bare, &MutationConfig::default());
+        assert_eq!(bare.get_args().count(), 0);
+    }
+
     #[test]
     fn execute_mutant_reports_capability_issue_or_missing_binary() {
         use crate::mutation::events::MutationType;
@@ -357,6 +666,7 @@ mod tests {
             selector: "s1".to_string(),
             source_file: String::new(),
             source_line: 0,
+            function: None,
             mutation_type: MutationType::Unknown,
             original_code: String::new(),
             mutated_code: String::new(),
@@ -1,9 +1,12 @@
 //! Event model for append-only mutation run logs.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Classification of mutation type for LLM-friendly analysis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MutationType {
     /// Arithmetic operator mutation (+, -, *, /, %).
@@ -25,15 +28,10 @@ pub enum MutationType {
     /// Negation insertion or removal.
     Negation,
     /// Unknown or unclassified mutation type.
+    #[default]
     Unknown,
 }
 
-impl Default for MutationType {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
 impl std::fmt::Display for MutationType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -66,6 +64,9 @@ pub struct MutantSpec {
     /// Source line number (1-indexed).
     #[serde(default)]
     pub source_line: u32,
+    /// Enclosing function or impl method, when the engine reports it.
+    #[serde(default)]
+    pub function: Option<String>,
     /// Classification of mutation type.
     #[serde(default)]
     pub mutation_type: MutationType,
@@ -108,6 +109,49 @@ pub enum MutationOutcome {
     },
 }
 
+/// Error returned when parsing an unrecognized status or outcome string.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown mutation status: {0:?}")]
+pub struct ParseStatusError(pub String);
+
+impl std::fmt::Display for MutationOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Killed => write!(f, "killed"),
+            Self::Survived => write!(f, "survived"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::Unviable => write!(f, "unviable"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Error { message } if message.is_empty() => write!(f, "error"),
+            Self::Error { message } => write!(f, "error: {message}"),
+        }
+    }
+}
+
+/// Parses the [`Display`](std::fmt::Display) form; `error: <message>` keeps the message.
+impl FromStr for MutationOutcome {
+    type Err = ParseStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "killed" => Ok(Self::Killed),
+            "survived" => Ok(Self::Survived),
+            "timeout" => Ok(Self::Timeout),
+            "unviable" => Ok(Self::Unviable),
+            "skipped" => Ok(Self::Skipped),
+            "error" => Ok(Self::Error {
+                message: String::new(),
+            }),
+            _ => s
+                .strip_prefix("error: ")
+                .map(|message| Self::Error {
+                    message: message.to_string(),
+                })
+                .ok_or_else(|| ParseStatusError(s.to_string())),
+        }
+    }
+}
+
 /// Snapshot of configuration for reproducibility.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RunConfigSnapshot {
@@ -123,6 +167,21 @@ pub struct RunConfigSnapshot {
     /// Quality gate maximum survived count.
     #[serde(default)]
     pub quality_gate_maximum_survived: Option<usize>,
+    /// Name of the mutation engine that produced the run.
+    #[serde(default)]
+    pub engine: Option<String>,
+    /// Workspace members the run was restricted to.
+    #[serde(default)]
+    pub packages: Vec<String>,
+    /// Base URL for report source links.
+    #[serde(default)]
+    pub source_base_url: Option<String>,
+    /// Extra arguments passed through to cargo-mutants.
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// Arguments passed through to the test binary after `--`.
+    #[serde(default)]
+    pub test_args: Vec<String>,
 }
 
 /// Environment metadata for reproducibility and debugging.
@@ -163,6 +222,12 @@ pub enum MutationEvent {
         timestamp_ms: i64,
         /// Number of mutants discovered for this run.
         discovered: usize,
+        /// Whether mutants are announced while the listing is still running.
+        ///
+        /// Streamed runs start with `discovered` at zero; the final count is recorded on
+        /// [`MutationEvent::RunCompleted`].
+        #[serde(default)]
+        discovery_streaming: bool,
         /// Configuration snapshot for reproducibility.
         #[serde(default)]
         config: Option<RunConfigSnapshot>,
@@ -253,9 +318,27 @@ pub enum MutationEvent {
         run_id: String,
         /// Unix timestamp millis.
         timestamp_ms: i64,
+        /// Final mutant count of a streamed discovery; `None` when `RunStarted` announced it.
+        #[serde(default)]
+        discovered: Option<usize>,
     },
 }
 
+impl MutationEvent {
+    /// Unix timestamp millis at which this event was recorded.
+    pub fn timestamp_ms(&self) -> i64 {
+        match self {
+            Self::RunStarted { timestamp_ms, .. }
+            | Self::RunResumed { timestamp_ms, .. }
+            | Self::MutantDiscovered { timestamp_ms, .. }
+            | Self::MutantStarted { timestamp_ms, .. }
+            | Self::MutantFinished { timestamp_ms, .. }
+            | Self::RunInterrupted { timestamp_ms, .. }
+            | Self::RunCompleted { timestamp_ms, .. } => *timestamp_ms,
+        }
+    }
+}
+
 /// Current unix timestamp in milliseconds.
 pub fn now_timestamp_ms() -> i64 {
     let now = std::time::SystemTime::now();
@@ -1,21 +1,29 @@
 //! Resumable mutation testing orchestration.
 
 pub mod config;
+pub mod diagnostics;
 pub mod engine;
 pub mod events;
+pub mod replay;
 /// Human-readable and machine-friendly report generation.
 pub mod report;
 pub mod runner;
 pub mod state;
 
-pub use config::MutationConfig;
-pub use engine::{CargoMutantsEngine, MutationEngine};
+pub use config::{ArtifactPolicy, MutationConfig, MutationTypeRules};
+pub use diagnostics::{assert_artifacts_present, assert_replay_order_independent};
+pub use engine::{CargoMutantsEngine, DryRunEngine, MutationEngine, stable_hash};
 pub use events::{
-    MutantSpec, MutationOutcome, MutationType, RunConfigSnapshot, RunMetadata, TestFailure,
-    collect_metadata, parse_mutation_type, truncate_preview,
+    MutantSpec, MutationOutcome, MutationType, ParseStatusError, RunConfigSnapshot, RunMetadata,
+    TestFailure, collect_metadata, parse_mutation_type, truncate_preview,
+};
+pub use replay::ReplayEngine;
+pub use report::{
+    GroupBy, MutantDetailReport, MutantReport, ReportFormat, RunSummary, render_grouped_report,
+    render_mutant_detail, render_prometheus, render_report, write_report,
 };
-pub use report::{MutantReport, ReportFormat, RunSummary, render_report};
 pub use runner::{
-    RunResult, load_run_status, render_run_report, rerun_survivors, resume_run, run_new,
+    RunResult, bundle_run, compact_run, load_run_status, render_run_report, rerun_survivors,
+    resume_run, run_and_summarize, run_new, run_warnings,
 };
 pub use state::{MutationStatus, RunInfo, RunSnapshot};
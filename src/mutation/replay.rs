@@ -0,0 +1,163 @@
+//! Engine that replays outcomes recorded by a previous run.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::config::MutationConfig;
+use super::engine::{MutantExecutionResult, MutationEngine, MutationEngineError};
+use super::events::{MutantSpec, MutationOutcome};
+use super::state::{MutationStateError, MutationStatus, RunSnapshot, replay_events};
+
+/// Mutation engine that answers from a prior run instead of executing anything.
+///
+/// Lets CI re-render reports or re-evaluate gates instantly. Discovery returns the recorded
+/// mutants; execution returns the recorded outcome, with stdout/stderr taken from the recorded
+/// previews. Mutants without a terminal outcome in the source run are unknown and fail with
+/// [`MutationEngineError::UnknownMutant`].
+#[derive(Debug, Clone, Default)]
+pub struct ReplayEngine {
+    mutants: Vec<MutantSpec>,
+    results: BTreeMap<String, MutantExecutionResult>,
+}
+
+impl ReplayEngine {
+    /// Build from a materialized snapshot.
+    pub fn from_snapshot(snapshot: &RunSnapshot) -> Self {
+        let mut results = BTreeMap::new();
+        for state in snapshot.mutants.values() {
+            let outcome = match &state.status {
+                MutationStatus::Killed => MutationOutcome::Killed,
+                MutationStatus::Survived => MutationOutcome::Survived,
+                MutationStatus::Timeout => MutationOutcome::Timeout,
+                MutationStatus::Unviable => MutationOutcome::Unviable,
+                MutationStatus::Skipped => MutationOutcome::Skipped,
+                MutationStatus::Error => MutationOutcome::Error {
+                    message: state.last_error.clone().unwrap_or_default(),
+                },
+                MutationStatus::Pending | MutationStatus::Running => continue,
+            };
+            results.insert(
+                state.spec.id.clone(),
+                MutantExecutionResult {
+                    outcome,
+                    exit_code: state.exit_code,
+                    stdout: state.stdout_preview.clone().unwrap_or_default(),
+                    stderr: state.stderr_preview.clone().unwrap_or_default(),
+                },
+            );
+        }
+
+        Self {
+            mutants: snapshot.mutants.values().map(|s| s.spec.clone()).collect(),
+            results,
+        }
+    }
+
+    /// Build from a prior run's `events.jsonl`.
+    pub fn from_events(events_path: &Path) -> Result<Self, MutationStateError> {
+        Ok(Self::from_snapshot(&replay_events(events_path)?))
+    }
+}
+
+impl MutationEngine for ReplayEngine {
+    fn name(&self) -> &str {
+        "replay"
+    }
+
+    fn discover_mutants(
+        &self,
+        _config: &MutationConfig,
+    ) -> Result<Vec<MutantSpec>, MutationEngineError> {
+        Ok(self.mutants.clone())
+    }
+
+    fn supports_concurrent_discovery(&self) -> bool {
+        true
+    }
+
+    fn execute_mutant(
+        &self,
+        _config: &MutationConfig,
+        mutant: &MutantSpec,
+    ) -> Result<MutantExecutionResult, MutationEngineError> {
+        self.results
+            .get(&mutant.id)
+            .cloned()
+            .ok_or_else(|| MutationEngineError::UnknownMutant(mutant.id.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::events::MutationType;
+    use crate::mutation::state::{MutantState, RunInfo};
+
+    fn state(id: &str, status: MutationStatus) -> MutantState {
+        MutantState {
+            spec: MutantSpec {
+                id: id.to_string(),
+                label: format!("label {id}"),
+                selector: id.to_string(),
+                source_file: String::new(),
+                source_line: 0,
+                function: None,
+                mutation_type: MutationType::Unknown,
+                original_code: String::new(),
+                mutated_code: String::new(),
+            },
+            status,
+            started_at_ms: None,
+            finished_at_ms: None,
+            duration_ms: None,
+            exit_code: Some(1),
+            stdout_artifact_path: None,
+            stderr_artifact_path: None,
+            last_error: None,
+            tests_run: Vec::new(),
+            tests_failed: Vec::new(),
+            stdout_preview: Some(format!("{id} stdout")),
+            stderr_preview: None,
+            previous_status: None,
+        }
+    }
+
+    #[test]
+    fn recorded_outcomes_are_replayed_and_unknown_mutants_fail() {
+        let snapshot = RunSnapshot {
+            run_id: "run-source".to_string(),
+            mutants: [
+                state("m1", MutationStatus::Killed),
+                state("m2", MutationStatus::Pending),
+            ]
+            .into_iter()
+            .map(|s| (s.spec.id.clone(), s))
+            .collect(),
+            malformed_lines: 0,
+            interrupted: true,
+            completed: false,
+            info: RunInfo::default(),
+            started_at_ms: None,
+            ended_at_ms: None,
+        };
+        let engine = ReplayEngine::from_snapshot(&snapshot);
+        let config = MutationConfig::default();
+
+        let discovered = engine
+            .discover_mutants(&config)
+            .expect("discovery should succeed");
+        assert_eq!(discovered.len(), 2);
+
+        let killed = engine
+            .execute_mutant(&config, &discovered[0])
+            .expect("m1 should be recorded");
+        assert_eq!(killed.outcome, MutationOutcome::Killed);
+        assert_eq!(killed.exit_code, Some(1));
+        assert_eq!(killed.stdout, "m1 stdout");
+
+        assert!(matches!(
+            engine.execute_mutant(&config, &discovered[1]),
+            Err(MutationEngineError::UnknownMutant(id)) if id == "m2"
+        ));
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
 use serde::Serialize;
 
 use super::events::{RunConfigSnapshot, RunMetadata};
@@ -14,6 +17,8 @@ pub enum ReportFormat {
     Sarif,
     /// JUnit XML format for CI systems.
     Junit,
+    /// Prometheus/OpenMetrics text exposition of the run summary.
+    Prometheus,
 }
 
 /// Per-mutant report entry for LLM-friendly JSON output.
@@ -27,6 +32,10 @@ pub struct MutantReport {
     pub source_file: String,
     /// Source line number.
     pub source_line: u32,
+    /// Link to the source line, when the run has a source base URL.
+    pub source_url: Option<String>,
+    /// Enclosing function, when known.
+    pub function: Option<String>,
     /// Mutation type classification.
     pub mutation_type: String,
     /// Original code snippet.
@@ -63,10 +72,12 @@ impl From<&MutantState> for MutantReport {
             label: state.spec.label.clone(),
             source_file: state.spec.source_file.clone(),
             source_line: state.spec.source_line,
+            source_url: None,
+            function: state.spec.function.clone(),
             mutation_type: state.spec.mutation_type.to_string(),
             original_code: state.spec.original_code.clone(),
             mutated_code: state.spec.mutated_code.clone(),
-            status: status_to_string(&state.status),
+            status: state.status.to_string(),
             duration_ms: state.duration_ms,
             tests_run: state.tests_run.clone(),
             tests_failed: state
@@ -83,17 +94,35 @@ impl From<&MutantState> for MutantReport {
     }
 }
 
-fn status_to_string(status: &MutationStatus) -> String {
-    match status {
-        MutationStatus::Pending => "pending".to_string(),
-        MutationStatus::Running => "running".to_string(),
-        MutationStatus::Killed => "killed".to_string(),
-        MutationStatus::Survived => "survived".to_string(),
-        MutationStatus::Timeout => "timeout".to_string(),
-        MutationStatus::Unviable => "unviable".to_string(),
-        MutationStatus::Skipped => "skipped".to_string(),
-        MutationStatus::Error => "error".to_string(),
+impl MutantReport {
+    /// Report entry with `source_url` resolved from the run's source base URL and git commit.
+    fn linked(snapshot: &RunSnapshot, state: &MutantState) -> Self {
+        let mut report = Self::from(state);
+        report.source_url = source_url(snapshot, state);
+        report
+    }
+}
+
+/// `{base}/{commit}/{file}#L{line}`, falling back to `HEAD` when the commit was not captured.
+fn source_url(snapshot: &RunSnapshot, state: &MutantState) -> Option<String> {
+    let base = snapshot.info.config.as_ref()?.source_base_url.as_deref()?;
+    if state.spec.source_file.is_empty() {
+        return None;
     }
+    let commit = snapshot
+        .info
+        .metadata
+        .as_ref()
+        .map(|meta| meta.git_commit.as_str())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or("HEAD");
+    Some(format!(
+        "{}/{}/{}#L{}",
+        base.trim_end_matches('/'),
+        commit,
+        state.spec.source_file.trim_start_matches("./"),
+        state.spec.source_line
+    ))
 }
 
 /// Run metadata for reports.
@@ -136,6 +165,12 @@ pub struct RunConfigReport {
     pub timeout_secs: Option<u64>,
     /// Filter applied.
     pub filter: Option<String>,
+    /// Mutation engine name.
+    pub engine: Option<String>,
+    /// Workspace members the run was restricted to.
+    pub packages: Vec<String>,
+    /// Base URL for source links.
+    pub source_base_url: Option<String>,
 }
 
 impl From<RunConfigSnapshot> for RunConfigReport {
@@ -143,6 +178,9 @@ impl From<RunConfigSnapshot> for RunConfigReport {
         Self {
             timeout_secs: c.timeout_secs,
             filter: c.filter,
+            engine: c.engine,
+            packages: c.packages,
+            source_base_url: c.source_base_url,
         }
     }
 }
@@ -168,13 +206,33 @@ pub struct RunSummary {
     pub error: usize,
     /// Still pending/running mutants.
     pub incomplete: usize,
+    /// Wall-clock span of the run's event log, if any events were recorded.
+    pub total_duration_ms: Option<u64>,
+    /// Terminal mutants per minute of wall-clock time.
+    pub mutants_per_minute: Option<f64>,
 }
 
 impl RunSummary {
     /// Build summary from snapshot.
     pub fn from_snapshot(snapshot: &RunSnapshot) -> Self {
+        let mut out = Self::from_mutants(snapshot.mutants.values());
+        out.total_duration_ms = snapshot.duration_ms();
+        out.mutants_per_minute = out
+            .total_duration_ms
+            .filter(|ms| *ms > 0)
+            .map(|ms| (out.total - out.incomplete) as f64 * 60_000.0 / ms as f64);
+        out
+    }
+
+    /// True when any mutant ended in `Error`, which points at tooling problems rather than
+    /// weak tests.
+    pub fn has_infra_errors(&self) -> bool {
+        self.error > 0
+    }
+
+    fn from_mutants<'a>(mutants: impl IntoIterator<Item = &'a MutantState>) -> Self {
         let mut out = Self {
-            total: snapshot.mutants.len(),
+            total: 0,
             mutation_score: 0.0,
             killed: 0,
             survived: 0,
@@ -183,9 +241,12 @@ impl RunSummary {
             skipped: 0,
             error: 0,
             incomplete: 0,
+            total_duration_ms: None,
+            mutants_per_minute: None,
         };
 
-        for mutant in snapshot.mutants.values() {
+        for mutant in mutants {
+            out.total += 1;
             match mutant.status {
                 MutationStatus::Killed => out.killed += 1,
                 MutationStatus::Survived => out.survived += 1,
@@ -208,26 +269,80 @@ impl RunSummary {
     }
 }
 
+/// JSON report body, serialized straight to the writer.
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    run_id: &'a str,
+    completed: bool,
+    interrupted: bool,
+    malformed_lines: usize,
+    config: Option<RunConfigReport>,
+    metadata: Option<RunMetadataReport>,
+    summary: &'a RunSummary,
+    mutants: LinkedMutants<'a>,
+}
+
+/// A run's mutants serialized as [`MutantReport`] entries built one at a time.
+struct LinkedMutants<'a>(&'a RunSnapshot);
+
+impl Serialize for LinkedMutants<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let snapshot = self.0;
+        serializer.collect_seq(
+            snapshot
+                .mutants
+                .values()
+                .map(|state| MutantReport::linked(snapshot, state)),
+        )
+    }
+}
+
 /// Render run report in requested format.
+///
+/// Output is deterministic: the same snapshot always renders to the same bytes, so reports are
+/// safe to golden-test and cache.
 pub fn render_report(snapshot: &RunSnapshot, format: ReportFormat) -> String {
-    let summary = RunSummary::from_snapshot(snapshot);
-    let mutants: Vec<MutantReport> = snapshot.mutants.values().map(MutantReport::from).collect();
+    let mut out = Vec::new();
+    write_report(snapshot, format, &mut out).expect("writing a report to memory should succeed");
+    String::from_utf8(out).expect("rendered report should be valid UTF-8")
+}
 
+/// Stream run report in requested format to `writer`.
+///
+/// JSON, Markdown and JUnit output builds each mutant's entry only when it is written, so large
+/// runs are never fully buffered. SARIF is rendered in memory.
+pub fn write_report<W: Write>(
+    snapshot: &RunSnapshot,
+    format: ReportFormat,
+    mut writer: W,
+) -> io::Result<()> {
+    let summary = RunSummary::from_snapshot(snapshot);
     let config = snapshot.info.config.clone().map(RunConfigReport::from);
     let metadata = snapshot.info.metadata.clone().map(RunMetadataReport::from);
+    let linked = || {
+        snapshot
+            .mutants
+            .values()
+            .map(|state| MutantReport::linked(snapshot, state))
+    };
 
     match format {
-        ReportFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
-            "run_id": snapshot.run_id,
-            "completed": snapshot.completed,
-            "interrupted": snapshot.interrupted,
-            "malformed_lines": snapshot.malformed_lines,
-            "config": config,
-            "metadata": metadata,
-            "summary": summary,
-            "mutants": mutants,
-        }))
-        .expect("report JSON should serialize"),
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(
+                &mut writer,
+                &JsonReport {
+                    run_id: &snapshot.run_id,
+                    completed: snapshot.completed,
+                    interrupted: snapshot.interrupted,
+                    malformed_lines: snapshot.malformed_lines,
+                    config,
+                    metadata,
+                    summary: &summary,
+                    mutants: LinkedMutants(snapshot),
+                },
+            )?;
+            Ok(())
+        }
         ReportFormat::Markdown => {
             let mut out = format!("# Mutation Run {}\n\n", snapshot.run_id);
 
@@ -247,7 +362,7 @@ pub fn render_report(snapshot: &RunSnapshot, format: ReportFormat) -> String {
                 if !meta.rustc_version.is_empty() {
                     out.push_str(&format!("- rustc: {}\n", meta.rustc_version));
                 }
-                out.push_str("\n");
+                out.push('\n');
             }
 
             out.push_str("## Summary\n\n| metric | count |\n|---|---:|\n");
@@ -263,56 +378,231 @@ pub fn render_report(snapshot: &RunSnapshot, format: ReportFormat) -> String {
                 "| mutation score | {:.2}% |\n",
                 summary.mutation_score
             ));
+            if let Some(ms) = summary.total_duration_ms {
+                out.push_str(&format!("| total duration | {}ms |\n", ms));
+            }
+            if let Some(rate) = summary.mutants_per_minute {
+                out.push_str(&format!("| mutants per minute | {:.2} |\n", rate));
+            }
 
-            if !mutants.is_empty() {
+            if !snapshot.mutants.is_empty() {
                 out.push_str("\n## Mutants\n\n");
-                for m in &mutants {
-                    out.push_str(&format!("### {}\n\n", m.id));
-                    out.push_str(&format!("- **label**: {}\n", m.label));
-                    if !m.source_file.is_empty() {
-                        out.push_str(&format!(
-                            "- **location**: {}:{}\n",
-                            m.source_file, m.source_line
-                        ));
-                    }
-                    out.push_str(&format!("- **type**: {}\n", m.mutation_type));
-                    out.push_str(&format!("- **status**: {}\n", m.status));
-                    if let Some(d) = m.duration_ms {
-                        out.push_str(&format!("- **duration**: {}ms\n", d));
-                    }
-                    if !m.original_code.is_empty() || !m.mutated_code.is_empty() {
-                        out.push_str("\n**diff**:\n");
-                        if !m.original_code.is_empty() {
-                            out.push_str(&format!("- original: `{}`\n", m.original_code));
-                        }
-                        if !m.mutated_code.is_empty() {
-                            out.push_str(&format!("- mutated: `{}`\n", m.mutated_code));
-                        }
-                    }
-                    if !m.tests_failed.is_empty() {
-                        out.push_str("\n**failed tests**:\n");
-                        for f in &m.tests_failed {
-                            out.push_str(&format!(
-                                "- {}{}\n",
-                                f.test_name,
-                                f.message
-                                    .as_ref()
-                                    .map(|m| format!(": {}", m))
-                                    .unwrap_or_default()
-                            ));
-                        }
-                    }
-                    out.push_str("\n");
-                }
             }
+            writer.write_all(out.as_bytes())?;
+
+            for m in linked() {
+                let mut section = format!("### {}\n\n", m.id);
+                push_mutant_markdown(&mut section, &m);
+                section.push('\n');
+                writer.write_all(section.as_bytes())?;
+            }
+            Ok(())
+        }
+        ReportFormat::Sarif => {
+            let mutants: Vec<MutantReport> = linked().collect();
+            writer.write_all(render_sarif_report(snapshot, &summary, &mutants).as_bytes())
+        }
+        ReportFormat::Junit => write_junit_report(snapshot, linked(), writer),
+        ReportFormat::Prometheus => {
+            writer.write_all(render_prometheus(&summary, &snapshot.run_id).as_bytes())
+        }
+    }
+}
+
+fn push_mutant_markdown(out: &mut String, m: &MutantReport) {
+    out.push_str(&format!("- **label**: {}\n", m.label));
+    if !m.source_file.is_empty() {
+        let location = format!("{}:{}", m.source_file, m.source_line);
+        match &m.source_url {
+            Some(url) => out.push_str(&format!("- **location**: [{location}]({url})\n")),
+            None => out.push_str(&format!("- **location**: {location}\n")),
+        }
+    }
+    if let Some(function) = &m.function {
+        out.push_str(&format!("- **function**: `{}`\n", function));
+    }
+    out.push_str(&format!("- **type**: {}\n", m.mutation_type));
+    out.push_str(&format!("- **status**: {}\n", m.status));
+    if let Some(d) = m.duration_ms {
+        out.push_str(&format!("- **duration**: {}ms\n", d));
+    }
+    if !m.original_code.is_empty() || !m.mutated_code.is_empty() {
+        out.push_str("\n**diff**:\n");
+        if !m.original_code.is_empty() {
+            out.push_str(&format!("- original: `{}`\n", m.original_code));
+        }
+        if !m.mutated_code.is_empty() {
+            out.push_str(&format!("- mutated: `{}`\n", m.mutated_code));
+        }
+    }
+    if !m.tests_failed.is_empty() {
+        out.push_str("\n**failed tests**:\n");
+        for f in &m.tests_failed {
+            out.push_str(&format!(
+                "- {}{}\n",
+                f.test_name,
+                f.message
+                    .as_ref()
+                    .map(|m| format!(": {}", m))
+                    .unwrap_or_default()
+            ));
+        }
+    }
+}
 
+/// Focused single-mutant view with execution details beyond the run-level entry.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MutantDetailReport {
+    /// Report entry shared with run-level reports.
+    #[serde(flatten)]
+    pub mutant: MutantReport,
+    /// Process exit code.
+    pub exit_code: Option<i32>,
+    /// Last recorded error message.
+    pub last_error: Option<String>,
+    /// Relative stdout artifact path.
+    pub stdout_artifact_path: Option<String>,
+    /// Relative stderr artifact path.
+    pub stderr_artifact_path: Option<String>,
+}
+
+impl From<&MutantState> for MutantDetailReport {
+    fn from(state: &MutantState) -> Self {
+        Self {
+            mutant: MutantReport::from(state),
+            exit_code: state.exit_code,
+            last_error: state.last_error.clone(),
+            stdout_artifact_path: state.stdout_artifact_path.clone(),
+            stderr_artifact_path: state.stderr_artifact_path.clone(),
+        }
+    }
+}
+
+/// Render a focused report for one mutant (diff, status, failing tests, output previews).
+///
+/// SARIF, JUnit, and Prometheus are run-level formats; for a single mutant they fall back to JSON.
+pub fn render_mutant_detail(state: &MutantState, format: ReportFormat) -> String {
+    let detail = MutantDetailReport::from(state);
+
+    match format {
+        ReportFormat::Json
+        | ReportFormat::Sarif
+        | ReportFormat::Junit
+        | ReportFormat::Prometheus => {
+            serde_json::to_string_pretty(&detail).expect("mutant detail JSON should serialize")
+        }
+        ReportFormat::Markdown => {
+            let m = &detail.mutant;
+            let mut out = format!("# Mutant {}\n\n", m.id);
+            push_mutant_markdown(&mut out, m);
+            if let Some(code) = detail.exit_code {
+                out.push_str(&format!("\n- **exit code**: {}\n", code));
+            }
+            if let Some(ref error) = detail.last_error {
+                out.push_str(&format!("\n**last error**:\n\n```text\n{}\n```\n", error));
+            }
+            if !m.tests_run.is_empty() {
+                out.push_str("\n**tests run**:\n");
+                for test in &m.tests_run {
+                    out.push_str(&format!("- {}\n", test));
+                }
+            }
+            for (label, preview) in [("stdout", &m.stdout_preview), ("stderr", &m.stderr_preview)] {
+                if let Some(text) = preview.as_deref().filter(|text| !text.is_empty()) {
+                    out.push_str(&format!(
+                        "\n**{label}**:\n\n```text\n{}\n```\n",
+                        text.trim_end()
+                    ));
+                }
+            }
             out
         }
-        ReportFormat::Sarif => render_sarif_report(snapshot, &summary, &mutants),
-        ReportFormat::Junit => render_junit_report(snapshot, &summary, &mutants),
     }
 }
 
+/// Grouping key for [`render_grouped_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    /// Group by mutated source file.
+    File,
+    /// Group by enclosing function.
+    Function,
+    /// Group by mutation operator category.
+    MutationType,
+    /// Group by mutant outcome status.
+    Status,
+}
+
+impl GroupBy {
+    fn label(self) -> &'static str {
+        match self {
+            Self::File => "file",
+            Self::Function => "function",
+            Self::MutationType => "mutation type",
+            Self::Status => "status",
+        }
+    }
+
+    fn key(self, state: &MutantState) -> String {
+        match self {
+            Self::File if state.spec.source_file.is_empty() => "(unknown file)".to_string(),
+            Self::File => state.spec.source_file.clone(),
+            Self::Function => state
+                .spec
+                .function
+                .clone()
+                .unwrap_or_else(|| "(unknown function)".to_string()),
+            Self::MutationType => state.spec.mutation_type.to_string(),
+            Self::Status => state.status.to_string(),
+        }
+    }
+}
+
+/// Render a Markdown report with one collapsible section per group.
+///
+/// Groups are ordered by key; each section header carries the group's mutant count and
+/// mutation score, so large runs can be reviewed file by file instead of as a flat list.
+pub fn render_grouped_report(snapshot: &RunSnapshot, group_by: GroupBy) -> String {
+    let mut groups: BTreeMap<String, Vec<&MutantState>> = BTreeMap::new();
+    for state in snapshot.mutants.values() {
+        groups.entry(group_by.key(state)).or_default().push(state);
+    }
+
+    let summary = RunSummary::from_snapshot(snapshot);
+    let mut out = format!(
+        "# Mutation Run {} (by {})\n\n",
+        snapshot.run_id,
+        group_by.label()
+    );
+    out.push_str(&format!(
+        "- mutants: {}\n- mutation score: {:.2}%\n- groups: {}\n",
+        summary.total,
+        summary.mutation_score,
+        groups.len()
+    ));
+
+    for (key, states) in &groups {
+        let group = RunSummary::from_mutants(states.iter().copied());
+        out.push_str(&format!(
+            "\n<details>\n<summary><b>{}</b> — {} mutants, score {:.2}% (killed {}, survived {})</summary>\n\n",
+            xml_escape(key),
+            group.total,
+            group.mutation_score,
+            group.killed,
+            group.survived
+        ));
+        for state in states {
+            let m = MutantReport::linked(snapshot, state);
+            out.push_str(&format!("### {}\n\n", m.id));
+            push_mutant_markdown(&mut out, &m);
+            out.push('\n');
+        }
+        out.push_str("</details>\n");
+    }
+
+    out
+}
+
 fn render_sarif_report(
     snapshot: &RunSnapshot,
     summary: &RunSummary,
@@ -378,21 +668,24 @@ fn render_sarif_report(
     .expect("SARIF JSON should serialize")
 }
 
-fn render_junit_report(
+fn write_junit_report<W: Write>(
     snapshot: &RunSnapshot,
-    _summary: &RunSummary,
-    mutants: &[MutantReport],
-) -> String {
+    mutants: impl Iterator<Item = MutantReport>,
+    mut writer: W,
+) -> io::Result<()> {
     let mut out = String::new();
     out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
     out.push_str("<testsuites>\n");
     out.push_str(&format!(
         "  <testsuite name=\"mutation-{}\" tests=\"{}\">\n",
         snapshot.run_id,
-        mutants.len()
+        snapshot.mutants.len()
     ));
 
+    writer.write_all(out.as_bytes())?;
+
     for m in mutants {
+        let mut out = String::new();
         let classname = if m.source_file.is_empty() {
             "mutation".to_string()
         } else {
@@ -422,7 +715,7 @@ fn render_junit_report(
                     "    <testcase classname=\"{}\" name=\"{}\">\n",
                     classname, m.id
                 ));
-                out.push_str(&format!("      <skipped message=\"Timeout\"/>\n"));
+                out.push_str("      <skipped message=\"Timeout\"/>\n");
                 out.push_str("    </testcase>\n");
             }
             _ => {
@@ -437,10 +730,69 @@ fn render_junit_report(
                 out.push_str("    </testcase>\n");
             }
         }
+        writer.write_all(out.as_bytes())?;
     }
 
-    out.push_str("  </testsuite>\n");
-    out.push_str("</testsuites>\n");
+    writer.write_all(b"  </testsuite>\n</testsuites>\n")
+}
+
+/// Render run summary as OpenMetrics text with a `run_id` label on every sample.
+pub fn render_prometheus(summary: &RunSummary, run_id: &str) -> String {
+    let metrics: [(&str, &str, f64); 9] = [
+        (
+            "mutation_score",
+            "Mutation score (killed / testable mutants), percentage.",
+            summary.mutation_score,
+        ),
+        (
+            "mutants_total",
+            "Total discovered mutants.",
+            summary.total as f64,
+        ),
+        ("mutants_killed", "Killed mutants.", summary.killed as f64),
+        (
+            "mutants_survived",
+            "Survived mutants.",
+            summary.survived as f64,
+        ),
+        (
+            "mutants_timeout",
+            "Timed out mutants.",
+            summary.timeout as f64,
+        ),
+        (
+            "mutants_unviable",
+            "Unviable mutants.",
+            summary.unviable as f64,
+        ),
+        (
+            "mutants_skipped",
+            "Skipped mutants.",
+            summary.skipped as f64,
+        ),
+        (
+            "mutants_error",
+            "Mutants that ended in an error.",
+            summary.error as f64,
+        ),
+        (
+            "mutants_incomplete",
+            "Pending or running mutants.",
+            summary.incomplete as f64,
+        ),
+    ];
+
+    let label = run_id
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    let mut out = String::new();
+    for (name, help, value) in metrics {
+        out.push_str(&format!("# HELP {name} {help}\n"));
+        out.push_str(&format!("# TYPE {name} gauge\n"));
+        out.push_str(&format!("{name}{{run_id=\"{label}\"}} {value}\n"));
+    }
+    out.push_str("# EOF\n");
     out
 }
 
@@ -454,11 +806,11 @@ fn xml_escape(s: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeMap;
-
     use super::*;
-    use crate::mutation::events::{MutantSpec, MutationType};
-    use crate::mutation::state::{MutantState, MutationStatus, RunInfo};
+    use crate::mutation::events::{MutantSpec, MutationEvent, MutationOutcome, MutationType};
+    use crate::mutation::state::{
+        MutantState, MutationStatus, RunInfo, append_event, replay_events,
+    };
 
     fn test_mutant(id: &str) -> MutantState {
         MutantState {
@@ -468,6 +820,7 @@ mod tests {
                 selector: "selector".to_string(),
                 source_file: String::new(),
                 source_line: 0,
+                function: None,
                 mutation_type: MutationType::Unknown,
                 original_code: String::new(),
                 mutated_code: String::new(),
@@ -484,6 +837,7 @@ mod tests {
             tests_failed: Vec::new(),
             stdout_preview: None,
             stderr_preview: None,
+            previous_status: None,
         }
     }
 
@@ -503,6 +857,8 @@ mod tests {
             interrupted: false,
             completed: true,
             info: RunInfo::default(),
+            started_at_ms: None,
+            ended_at_ms: None,
         }
     }
 
@@ -528,6 +884,10 @@ mod tests {
         assert_eq!(summary.error, 1);
         assert_eq!(summary.incomplete, 2);
         assert!((summary.mutation_score - (1.0 / 6.0 * 100.0)).abs() < 1e-12);
+        assert!(summary.has_infra_errors());
+
+        let clean = snapshot_with_statuses(&[MutationStatus::Killed, MutationStatus::Survived]);
+        assert!(!RunSummary::from_snapshot(&clean).has_infra_errors());
     }
 
     #[test]
@@ -568,4 +928,292 @@ mod tests {
         assert!(junit.contains("<testsuites>"));
         assert!(junit.contains("<failure"));
     }
+
+    #[test]
+    fn markdown_links_source_lines_at_the_run_commit() {
+        let mut snapshot = snapshot_with_statuses(&[MutationStatus::Survived]);
+        let state = snapshot.mutants.get_mut("m0").expect("mutant m0");
+        state.spec.source_file = "src/lib.rs".to_string();
+        state.spec.source_line = 42;
+
+        let unlinked = render_report(&snapshot, ReportFormat::Markdown);
+        assert!(unlinked.contains("- **location**: src/lib.rs:42\n"));
+
+        snapshot.info.config = Some(RunConfigSnapshot {
+            timeout_secs: None,
+            filter: None,
+            quality_gate_minimum_score: None,
+            quality_gate_maximum_survived: None,
+            engine: None,
+            packages: Vec::new(),
+            source_base_url: Some("https://github.com/o/r/blob/".to_string()),
+            extra_args: Vec::new(),
+            test_args: Vec::new(),
+        });
+        snapshot.info.metadata = Some(
+            serde_json::from_value(serde_json::json!({ "git_commit": "abc123" }))
+                .expect("metadata should deserialize"),
+        );
+
+        let markdown = render_report(&snapshot, ReportFormat::Markdown);
+        assert!(
+            markdown.contains(
+                "- **location**: [src/lib.rs:42](https://github.com/o/r/blob/abc123/src/lib.rs#L42)"
+            ),
+            "{markdown}"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render_report(&snapshot, ReportFormat::Json))
+                .expect("json report should parse");
+        assert_eq!(
+            json["mutants"][0]["source_url"],
+            "https://github.com/o/r/blob/abc123/src/lib.rs#L42"
+        );
+    }
+
+    #[test]
+    fn mutant_detail_renders_diff_status_and_failures() {
+        let mut state = test_mutant("m7");
+        state.status = MutationStatus::Killed;
+        state.spec.source_file = "src/lib.rs".to_string();
+        state.spec.source_line = 42;
+        state.spec.function = Some("Calc::add".to_string());
+        state.spec.original_code = "a + b".to_string();
+        state.spec.mutated_code = "a - b".to_string();
+        state.exit_code = Some(3);
+        state.tests_failed = vec![crate::mutation::events::TestFailure {
+            test_name: "adds_numbers".to_string(),
+            message: Some("left != right".to_string()),
+        }];
+        state.stdout_preview = Some("test adds_numbers ... FAILED".to_string());
+
+        let md = render_mutant_detail(&state, ReportFormat::Markdown);
+        assert!(md.starts_with("# Mutant m7"));
+        assert!(md.contains("- **status**: killed"));
+        assert!(md.contains("- **location**: src/lib.rs:42"));
+        assert!(md.contains("- **function**: `Calc::add`"));
+        assert!(md.contains("- original: `a + b`"));
+        assert!(md.contains("- mutated: `a - b`"));
+        assert!(md.contains("- adds_numbers: left != right"));
+        assert!(md.contains("test adds_numbers ... FAILED"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_mutant_detail(&state, ReportFormat::Json))
+                .expect("detail should be valid JSON");
+        assert_eq!(json["id"], "m7");
+        assert_eq!(json["status"], "killed");
+        assert_eq!(json["mutated_code"], "a - b");
+        assert_eq!(json["exit_code"], 3);
+    }
+
+    #[test]
+    fn report_renders_prometheus_format() {
+        let snapshot = snapshot_with_statuses(&[MutationStatus::Survived, MutationStatus::Killed]);
+        let metrics = render_report(&snapshot, ReportFormat::Prometheus);
+        for name in [
+            "mutation_score",
+            "mutants_total",
+            "mutants_killed",
+            "mutants_survived",
+            "mutants_timeout",
+            "mutants_unviable",
+            "mutants_skipped",
+            "mutants_error",
+            "mutants_incomplete",
+        ] {
+            assert!(metrics.contains(&format!("# HELP {name} ")));
+            assert!(metrics.contains(&format!("# TYPE {name} gauge\n")));
+        }
+        assert!(metrics.contains("mutants_total{run_id=\"run-report\"} 2\n"));
+        assert!(metrics.contains("mutants_survived{run_id=\"run-report\"} 1\n"));
+        assert!(metrics.contains("mutation_score{run_id=\"run-report\"} 50\n"));
+        assert!(metrics.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn write_report_matches_render_report_for_every_format() {
+        let snapshot = snapshot_with_statuses(&[
+            MutationStatus::Killed,
+            MutationStatus::Survived,
+            MutationStatus::Timeout,
+            MutationStatus::Pending,
+        ]);
+        for format in [
+            ReportFormat::Markdown,
+            ReportFormat::Json,
+            ReportFormat::Sarif,
+            ReportFormat::Junit,
+            ReportFormat::Prometheus,
+        ] {
+            let mut buffer: Vec<u8> = Vec::new();
+            write_report(&snapshot, format, &mut buffer).expect("write should succeed");
+            assert_eq!(
+                String::from_utf8(buffer).expect("report should be UTF-8"),
+                render_report(&snapshot, format),
+                "streamed {format:?} report differs from rendered string"
+            );
+        }
+    }
+
+    #[test]
+    fn render_report_is_byte_identical_across_renders_for_every_format() {
+        let statuses = [
+            MutationStatus::Killed,
+            MutationStatus::Survived,
+            MutationStatus::Timeout,
+            MutationStatus::Error,
+            MutationStatus::Pending,
+        ];
+        let first = snapshot_with_statuses(&statuses);
+        let mut second = snapshot_with_statuses(&[]);
+        for (id, state) in first.mutants.iter().rev() {
+            second.mutants.insert(id.clone(), state.clone());
+        }
+
+        for format in [
+            ReportFormat::Markdown,
+            ReportFormat::Json,
+            ReportFormat::Sarif,
+            ReportFormat::Junit,
+            ReportFormat::Prometheus,
+        ] {
+            let rendered = render_report(&first, format);
+            assert_eq!(
+                rendered,
+                render_report(&first, format),
+                "{format:?} report differs between two renders of the same snapshot"
+            );
+            assert_eq!(
+                rendered,
+                render_report(&second, format),
+                "{format:?} report depends on mutant insertion order"
+            );
+        }
+    }
+
+    #[test]
+    fn grouped_report_has_one_section_per_file_with_counts() {
+        let mut snapshot = snapshot_with_statuses(&[
+            MutationStatus::Killed,
+            MutationStatus::Survived,
+            MutationStatus::Killed,
+            MutationStatus::Skipped,
+        ]);
+        for (id, file) in [
+            ("m0", "src/a.rs"),
+            ("m1", "src/a.rs"),
+            ("m2", "src/b.rs"),
+            ("m3", "src/c.rs"),
+        ] {
+            snapshot
+                .mutants
+                .get_mut(id)
+                .expect("mutant should exist")
+                .spec
+                .source_file = file.to_string();
+        }
+
+        let report = render_grouped_report(&snapshot, GroupBy::File);
+        assert!(report.starts_with("# Mutation Run run-report (by file)"));
+        assert!(report.contains("- groups: 3\n"));
+        assert_eq!(report.matches("<details>").count(), 3);
+        assert_eq!(report.matches("</details>").count(), 3);
+        assert!(
+            report.contains("<b>src/a.rs</b> — 2 mutants, score 50.00% (killed 1, survived 1)")
+        );
+        assert!(
+            report.contains("<b>src/b.rs</b> — 1 mutants, score 100.00% (killed 1, survived 0)")
+        );
+        assert!(
+            report.contains("<b>src/c.rs</b> — 1 mutants, score 100.00% (killed 0, survived 0)")
+        );
+        assert!(report.find("src/a.rs").unwrap() < report.find("src/b.rs").unwrap());
+
+        snapshot
+            .mutants
+            .get_mut("m1")
+            .expect("mutant should exist")
+            .spec
+            .function = Some("parse".to_string());
+        let by_function = render_grouped_report(&snapshot, GroupBy::Function);
+        assert!(
+            by_function.contains("<b>parse</b> — 1 mutants, score 0.00% (killed 0, survived 1)")
+        );
+        assert!(by_function.contains("<b>(unknown function)</b> — 3 mutants"));
+
+        snapshot
+            .mutants
+            .get_mut("m1")
+            .expect("mutant should exist")
+            .spec
+            .function = Some("<impl Display for Money>::fmt".to_string());
+        let by_impl = render_grouped_report(&snapshot, GroupBy::Function);
+        assert!(by_impl.contains("<b>&lt;impl Display for Money&gt;::fmt</b> — 1 mutants"));
+        assert!(by_impl.contains("- **function**: `<impl Display for Money>::fmt`\n"));
+        assert!(!by_impl.contains("<impl Display for Money>::fmt</b>"));
+
+        let by_status = render_grouped_report(&snapshot, GroupBy::Status);
+        assert_eq!(by_status.matches("<details>").count(), 3);
+        assert!(by_status.contains("<b>killed</b> — 2 mutants"));
+    }
+
+    #[test]
+    fn run_timing_comes_from_first_and_last_events() {
+        let tmp = tempfile::tempdir().expect("tempdir should be created");
+        let events_path = tmp.path().join("events.jsonl");
+        let spec = test_mutant("m1").spec;
+        let events = [
+            MutationEvent::RunStarted {
+                run_id: "run-timing".to_string(),
+                timestamp_ms: 1_000,
+                discovered: 1,
+                discovery_streaming: false,
+                config: None,
+                metadata: None,
+            },
+            MutationEvent::MutantDiscovered {
+                run_id: "run-timing".to_string(),
+                timestamp_ms: 1_500,
+                mutant: spec,
+            },
+            MutationEvent::MutantFinished {
+                run_id: "run-timing".to_string(),
+                timestamp_ms: 20_000,
+                mutant_id: "m1".to_string(),
+                outcome: MutationOutcome::Killed,
+                exit_code: Some(1),
+                stdout_artifact_path: None,
+                stderr_artifact_path: None,
+                started_at_ms: None,
+                finished_at_ms: None,
+                duration_ms: None,
+                tests_run: Vec::new(),
+                tests_failed: Vec::new(),
+                stdout_preview: None,
+                stderr_preview: None,
+            },
+            MutationEvent::RunCompleted {
+                run_id: "run-timing".to_string(),
+                timestamp_ms: 31_000,
+                discovered: None,
+            },
+        ];
+        for event in &events {
+            append_event(&events_path, event).expect("event should append");
+        }
+
+        let snapshot = replay_events(&events_path).expect("replay should succeed");
+        assert_eq!(snapshot.started_at_ms, Some(1_000));
+        assert_eq!(snapshot.ended_at_ms, Some(31_000));
+
+        let summary = RunSummary::from_snapshot(&snapshot);
+        assert_eq!(summary.total_duration_ms, Some(30_000));
+        assert_eq!(summary.mutants_per_minute, Some(2.0));
+
+        let markdown = render_report(&snapshot, ReportFormat::Markdown);
+        assert!(markdown.contains("| total duration | 30000ms |"));
+        assert!(markdown.contains("| mutants per minute | 2.00 |"));
+        let json = render_report(&snapshot, ReportFormat::Json);
+        assert!(json.contains("\"total_duration_ms\": 30000"));
+    }
 }
@@ -6,13 +6,19 @@ use std::sync::OnceLock;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::Instant;
 
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use thiserror::Error;
 
-use super::config::MutationConfig;
+use super::config::{ArtifactPolicy, MutationConfig};
 use super::engine::{MutantExecutionResult, MutationEngine, MutationEngineError};
-use super::events::{MutantSpec, MutationEvent, MutationOutcome, now_timestamp_ms};
-use super::report::{ReportFormat, render_report};
-use super::state::{MutationStateError, RunSnapshot, append_event, replay_events};
+use super::events::{
+    MutantSpec, MutationEvent, MutationOutcome, RunConfigSnapshot, now_timestamp_ms,
+};
+use super::report::{ReportFormat, RunSummary, render_report};
+use super::state::{
+    MutationStateError, MutationStatus, RunSnapshot, append_event, compact_events, replay_events,
+};
 
 static INTERRUPTED: AtomicBool = AtomicBool::new(false);
 static RUN_SEQUENCE: AtomicU64 = AtomicU64::new(0);
@@ -87,7 +93,12 @@ fn write_mutant_artifacts(
     run_dir: &Path,
     mutant_id: &str,
     result: &MutantExecutionResult,
+    policy: ArtifactPolicy,
 ) -> Result<(Option<String>, Option<String>), MutationRunError> {
+    if !policy.allows(&result.outcome) {
+        return Ok((None, None));
+    }
+
     let base = run_dir.join("artifacts");
     std::fs::create_dir_all(&base)?;
 
@@ -130,7 +141,35 @@ fn parse_run_id_key(run_id: &str) -> Option<RunIdKey> {
     })
 }
 
-fn is_snapshot_compatible(snapshot: &RunSnapshot, config: &MutationConfig) -> bool {
+fn config_snapshot(config: &MutationConfig, engine: &dyn MutationEngine) -> RunConfigSnapshot {
+    RunConfigSnapshot {
+        timeout_secs: config.timeout_secs,
+        filter: config.filter.clone(),
+        quality_gate_minimum_score: None,
+        quality_gate_maximum_survived: None,
+        engine: Some(engine.name().to_string()),
+        packages: config.packages.clone(),
+        source_base_url: config.source_base_url.clone(),
+        extra_args: config.extra_args.clone(),
+        test_args: config.test_args.clone(),
+    }
+}
+
+fn is_snapshot_compatible(
+    snapshot: &RunSnapshot,
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> bool {
+    // Runs recorded before engine names were persisted stay resumable.
+    let snapshot_engine = snapshot
+        .info
+        .config
+        .as_ref()
+        .and_then(|cfg| cfg.engine.as_deref());
+    if snapshot_engine.is_some_and(|name| name != engine.name()) {
+        return false;
+    }
+
     let snapshot_filter = snapshot
         .info
         .config
@@ -140,6 +179,27 @@ fn is_snapshot_compatible(snapshot: &RunSnapshot, config: &MutationConfig) -> bo
         return false;
     }
 
+    let snapshot_packages = snapshot
+        .info
+        .config
+        .as_ref()
+        .map(|cfg| cfg.packages.as_slice())
+        .unwrap_or_default();
+    if snapshot_packages != config.packages.as_slice() {
+        return false;
+    }
+
+    // Different features or test flags can change outcomes, so results must not mix.
+    let snapshot_args = snapshot
+        .info
+        .config
+        .as_ref()
+        .map(|cfg| (cfg.extra_args.as_slice(), cfg.test_args.as_slice()))
+        .unwrap_or_default();
+    if snapshot_args != (config.extra_args.as_slice(), config.test_args.as_slice()) {
+        return false;
+    }
+
     let snapshot_timeout = snapshot
         .info
         .config
@@ -156,7 +216,10 @@ fn is_newer_run_id(candidate: &RunIdKey, current: &RunIdKey) -> bool {
             && candidate.sequence > current.sequence)
 }
 
-fn latest_incomplete_run_id(config: &MutationConfig) -> Result<Option<String>, MutationRunError> {
+fn latest_incomplete_run_id(
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> Result<Option<String>, MutationRunError> {
     if !config.run_root.exists() {
         return Ok(None);
     }
@@ -181,11 +244,13 @@ fn latest_incomplete_run_id(config: &MutationConfig) -> Result<Option<String>, M
             Err(err) => return Err(err),
         };
 
-        if snapshot.completed || snapshot.pending_mutants().is_empty() {
+        // Unfinished streamed discovery still has work even when every known mutant ran.
+        let has_work = !snapshot.pending_mutants().is_empty() || snapshot.info.discovery_streaming;
+        if snapshot.completed || !has_work {
             continue;
         }
 
-        if !is_snapshot_compatible(&snapshot, config) {
+        if !is_snapshot_compatible(&snapshot, config, engine) {
             continue;
         }
 
@@ -204,6 +269,7 @@ fn latest_incomplete_run_id(config: &MutationConfig) -> Result<Option<String>, M
 
 fn latest_completed_run_with_survivors_id(
     config: &MutationConfig,
+    engine: &dyn MutationEngine,
 ) -> Result<Option<String>, MutationRunError> {
     if !config.run_root.exists() {
         return Ok(None);
@@ -233,7 +299,7 @@ fn latest_completed_run_with_survivors_id(
             continue;
         }
 
-        if !is_snapshot_compatible(&snapshot, config) {
+        if !is_snapshot_compatible(&snapshot, config, engine) {
             continue;
         }
 
@@ -250,6 +316,75 @@ fn latest_completed_run_with_survivors_id(
     Ok(newest.map(|(_, run_id)| run_id))
 }
 
+/// Newest compatible completed run, returned only when it has no survivors or errors.
+fn latest_completed_clean_run(
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> Result<Option<(String, RunSnapshot)>, MutationRunError> {
+    if !config.run_root.exists() {
+        return Ok(None);
+    }
+
+    let mut newest: Option<(RunIdKey, String, RunSnapshot)> = None;
+
+    for entry in std::fs::read_dir(&config.run_root)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let run_id = entry.file_name().to_string_lossy().to_string();
+        let run_id_key = match parse_run_id_key(&run_id) {
+            Some(key) => key,
+            None => continue,
+        };
+
+        let snapshot = match load_run_status(config, &run_id) {
+            Ok(snapshot) => snapshot,
+            Err(MutationRunError::State(_)) => continue,
+            Err(err) => return Err(err),
+        };
+
+        if !snapshot.completed || !is_snapshot_compatible(&snapshot, config, engine) {
+            continue;
+        }
+
+        let is_newer = match &newest {
+            Some((current, _, _)) => is_newer_run_id(&run_id_key, current),
+            None => true,
+        };
+
+        if is_newer {
+            newest = Some((run_id_key, run_id, snapshot));
+        }
+    }
+
+    Ok(newest.and_then(|(_, run_id, snapshot)| {
+        let clean = snapshot.survivor_mutants().is_empty()
+            && snapshot
+                .mutants
+                .values()
+                .all(|state| !matches!(state.status, MutationStatus::Error));
+        clean.then_some((run_id, snapshot))
+    }))
+}
+
+/// Apply the configured filter and classification rules; `None` when `mutant` is filtered out.
+fn select_mutant(config: &MutationConfig, mut mutant: MutantSpec) -> Option<MutantSpec> {
+    if let Some(filter) = &config.filter {
+        if !(mutant.id.contains(filter)
+            || mutant.label.contains(filter)
+            || mutant.selector.contains(filter))
+        {
+            return None;
+        }
+    }
+    if let Some(mutation_type) = config.classify_mutation_type(&mutant.label) {
+        mutant.mutation_type = mutation_type;
+    }
+    Some(mutant)
+}
+
 fn run_mutant(
     run_id: &str,
     run_dir: &Path,
@@ -284,7 +419,7 @@ fn run_mutant(
     let finished_at_ms = now_timestamp_ms();
     let duration_ms = started.elapsed().as_millis() as u64;
     let (stdout_artifact_path, stderr_artifact_path) =
-        write_mutant_artifacts(run_dir, &mutant.id, &execution)?;
+        write_mutant_artifacts(run_dir, &mutant.id, &execution, config.artifact_policy)?;
 
     append_event(
         events,
@@ -309,35 +444,18 @@ fn run_mutant(
     Ok(())
 }
 
-/// Start a new mutation run.
-pub fn run_new(
+/// Finish the listing, then announce and execute every selected mutant.
+fn run_listed_mutants(
+    run_id: &str,
+    run_dir: &Path,
+    events: &Path,
     config: &MutationConfig,
     engine: &dyn MutationEngine,
-) -> Result<RunResult, MutationRunError> {
-    install_signal_handler_once()?;
-    INTERRUPTED.store(false, Ordering::SeqCst);
-
-    if let Some(run_id) = latest_incomplete_run_id(config)? {
-        println!("kitchensink-testing: resuming interrupted run {run_id}");
-        return resume_run(config, &run_id, engine);
-    }
-
-    if let Some(run_id) = latest_completed_run_with_survivors_id(config)? {
-        println!("kitchensink-testing: retesting survivors from completed run {run_id}");
-        return rerun_survivors(config, &run_id, engine);
-    }
-
-    let run_id = generate_run_id();
-    let run_dir = config.run_root.join(&run_id);
-    std::fs::create_dir_all(&run_dir)?;
-    let events = events_path(&run_dir);
-
-    let mut mutants = engine.discover_mutants(config)?;
-    if let Some(filter) = &config.filter {
-        mutants.retain(|m| {
-            m.id.contains(filter) || m.label.contains(filter) || m.selector.contains(filter)
-        });
-    }
+) -> Result<(), MutationRunError> {
+    let mut mutants = Vec::new();
+    engine.discover_mutants_streaming(config, &mut |mutant| {
+        mutants.extend(select_mutant(config, mutant));
+    })?;
     println!(
         "kitchensink-testing: discovered {} mutant(s) in {}",
         mutants.len(),
@@ -345,33 +463,28 @@ pub fn run_new(
     );
 
     append_event(
-        &events,
+        events,
         &MutationEvent::RunStarted {
-            run_id: run_id.clone(),
+            run_id: run_id.to_string(),
             timestamp_ms: now_timestamp_ms(),
             discovered: mutants.len(),
-            config: Some(super::events::RunConfigSnapshot {
-                timeout_secs: config.timeout_secs,
-                filter: config.filter.clone(),
-                quality_gate_minimum_score: None,
-                quality_gate_maximum_survived: None,
-            }),
+            discovery_streaming: false,
+            config: Some(config_snapshot(config, engine)),
             metadata: Some(super::events::collect_metadata()),
         },
     )?;
-
-    let total_mutants = mutants.len();
     for mutant in &mutants {
         append_event(
-            &events,
+            events,
             &MutationEvent::MutantDiscovered {
-                run_id: run_id.clone(),
+                run_id: run_id.to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 mutant: mutant.clone(),
             },
         )?;
     }
 
+    let total_mutants = mutants.len();
     for (index, mutant) in mutants.iter().enumerate() {
         let position = index + 1;
         println!(
@@ -380,24 +493,174 @@ pub fn run_new(
         );
         if INTERRUPTED.load(Ordering::SeqCst) {
             append_event(
-                &events,
+                events,
                 &MutationEvent::RunInterrupted {
-                    run_id: run_id.clone(),
+                    run_id: run_id.to_string(),
                     timestamp_ms: now_timestamp_ms(),
                     reason: "received interrupt signal".to_string(),
                 },
             )?;
             break;
         }
-        run_mutant(&run_id, &run_dir, &events, config, engine, mutant)?;
+        run_mutant(run_id, run_dir, events, config, engine, mutant)?;
+    }
+    Ok(())
+}
+
+/// Announce and execute each selected mutant as the engine streams it; returns the final count.
+///
+/// Only used for engines that support concurrent discovery. A listing cut short by an interrupt
+/// or a failure leaves the run resumable, and resume finishes the discovery.
+fn run_streamed_mutants(
+    run_id: &str,
+    run_dir: &Path,
+    events: &Path,
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> Result<usize, MutationRunError> {
+    append_event(
+        events,
+        &MutationEvent::RunStarted {
+            run_id: run_id.to_string(),
+            timestamp_ms: now_timestamp_ms(),
+            discovered: 0,
+            discovery_streaming: true,
+            config: Some(config_snapshot(config, engine)),
+            metadata: Some(super::events::collect_metadata()),
+        },
+    )?;
+
+    let mut announced = 0;
+    let mut interrupted = false;
+    let mut on_mutant = |mutant: MutantSpec| -> Result<(), MutationRunError> {
+        announced += 1;
+        append_event(
+            events,
+            &MutationEvent::MutantDiscovered {
+                run_id: run_id.to_string(),
+                timestamp_ms: now_timestamp_ms(),
+                mutant: mutant.clone(),
+            },
+        )?;
+        // Mutants announced after an interrupt stay pending for resume.
+        if interrupted {
+            return Ok(());
+        }
+        if INTERRUPTED.load(Ordering::SeqCst) {
+            interrupted = true;
+            return append_event(
+                events,
+                &MutationEvent::RunInterrupted {
+                    run_id: run_id.to_string(),
+                    timestamp_ms: now_timestamp_ms(),
+                    reason: "received interrupt signal".to_string(),
+                },
+            )
+            .map_err(Into::into);
+        }
+        println!(
+            "kitchensink-testing: running mutant {announced}: {}",
+            mutant.label
+        );
+        run_mutant(run_id, run_dir, events, config, engine, &mutant)
+    };
+    let mut failure = None;
+    let listing = engine.discover_mutants_streaming(config, &mut |mutant| {
+        if failure.is_none() {
+            if let Some(mutant) = select_mutant(config, mutant) {
+                failure = on_mutant(mutant).err();
+            }
+        }
+    });
+    if let Some(err) = failure {
+        return Err(err);
+    }
+
+    match listing {
+        Ok(_) => {}
+        // The listing died with the same interrupt; resume finishes discovery.
+        Err(_) if INTERRUPTED.load(Ordering::SeqCst) => {
+            if !interrupted {
+                append_event(
+                    events,
+                    &MutationEvent::RunInterrupted {
+                        run_id: run_id.to_string(),
+                        timestamp_ms: now_timestamp_ms(),
+                        reason: "received interrupt signal during discovery".to_string(),
+                    },
+                )?;
+            }
+        }
+        // Leave the partial run resumable rather than letting it pass for a complete one.
+        Err(err) => {
+            append_event(
+                events,
+                &MutationEvent::RunInterrupted {
+                    run_id: run_id.to_string(),
+                    timestamp_ms: now_timestamp_ms(),
+                    reason: format!("mutant discovery failed: {err}"),
+                },
+            )?;
+            return Err(err.into());
+        }
+    }
+    println!(
+        "kitchensink-testing: discovered {announced} mutant(s) in {}",
+        config.project_dir.display()
+    );
+    Ok(announced)
+}
+
+/// Start a new mutation run.
+pub fn run_new(
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> Result<RunResult, MutationRunError> {
+    install_signal_handler_once()?;
+    INTERRUPTED.store(false, Ordering::SeqCst);
+
+    if let Some(run_id) = latest_incomplete_run_id(config, engine)? {
+        println!("kitchensink-testing: resuming interrupted run {run_id}");
+        return resume_run(config, &run_id, engine);
+    }
+
+    if config.reuse_clean_runs {
+        if let Some((run_id, snapshot)) = latest_completed_clean_run(config, engine)? {
+            println!("kitchensink-testing: latest run {run_id} completed clean; nothing to do");
+            return Ok(RunResult {
+                run_dir: config.run_root.join(&run_id),
+                run_id,
+                snapshot,
+            });
+        }
     }
 
+    if let Some(run_id) = latest_completed_run_with_survivors_id(config, engine)? {
+        println!("kitchensink-testing: retesting survivors from completed run {run_id}");
+        return rerun_survivors(config, &run_id, engine);
+    }
+
+    let run_id = generate_run_id();
+    let run_dir = config.run_root.join(&run_id);
+    std::fs::create_dir_all(&run_dir)?;
+    let events = events_path(&run_dir);
+
+    let streamed = if engine.supports_concurrent_discovery() {
+        Some(run_streamed_mutants(
+            &run_id, &run_dir, &events, config, engine,
+        )?)
+    } else {
+        run_listed_mutants(&run_id, &run_dir, &events, config, engine)?;
+        None
+    };
+
     if !INTERRUPTED.load(Ordering::SeqCst) {
         append_event(
             &events,
             &MutationEvent::RunCompleted {
                 run_id: run_id.clone(),
                 timestamp_ms: now_timestamp_ms(),
+                discovered: streamed,
             },
         )?;
     }
@@ -410,6 +673,18 @@ pub fn run_new(
     })
 }
 
+/// Run in-process via [`run_new`] and summarize the resulting snapshot in one call.
+///
+/// For embedders that want a [`RunSummary`] without going through the CLI.
+pub fn run_and_summarize(
+    config: &MutationConfig,
+    engine: &dyn MutationEngine,
+) -> Result<(RunResult, RunSummary), MutationRunError> {
+    let run = run_new(config, engine)?;
+    let summary = RunSummary::from_snapshot(&run.snapshot);
+    Ok((run, summary))
+}
+
 /// Resume an existing run id.
 pub fn resume_run(
     config: &MutationConfig,
@@ -421,7 +696,33 @@ pub fn resume_run(
 
     let run_dir = config.run_root.join(run_id);
     let events = events_path(&run_dir);
-    let snapshot = replay_events(&events)?;
+    let mut snapshot = replay_events(&events)?;
+
+    // A streamed discovery cut short by an interrupt or a failed listing is finished first, so
+    // the resumed run covers every mutant. The listing completes before anything executes.
+    let mut streamed = None;
+    if !snapshot.completed && snapshot.info.discovery_streaming {
+        let mut discovered = 0;
+        for mutant in engine.discover_mutants(config)? {
+            let Some(mutant) = select_mutant(config, mutant) else {
+                continue;
+            };
+            discovered += 1;
+            if !snapshot.mutants.contains_key(&mutant.id) {
+                append_event(
+                    &events,
+                    &MutationEvent::MutantDiscovered {
+                        run_id: run_id.to_string(),
+                        timestamp_ms: now_timestamp_ms(),
+                        mutant,
+                    },
+                )?;
+            }
+        }
+        streamed = Some(discovered);
+        snapshot = replay_events(&events)?;
+    }
+
     let survivors = snapshot.survivor_mutants();
     let pending = snapshot.pending_mutants();
 
@@ -498,6 +799,7 @@ pub fn resume_run(
             &MutationEvent::RunCompleted {
                 run_id: run_id.to_string(),
                 timestamp_ms: now_timestamp_ms(),
+                discovered: streamed,
             },
         )?;
     }
@@ -576,7 +878,45 @@ pub fn rerun_survivors(
     })
 }
 
+/// Artifact paths recorded as absolute, which stop resolving once a run directory moves.
+fn absolute_artifact_paths(snapshot: &RunSnapshot) -> Vec<(String, String)> {
+    snapshot
+        .mutants
+        .values()
+        .flat_map(|state| {
+            [&state.stdout_artifact_path, &state.stderr_artifact_path]
+                .into_iter()
+                .flatten()
+                .filter(|path| Path::new(path).is_absolute())
+                .map(|path| (state.spec.id.clone(), path.clone()))
+        })
+        .collect()
+}
+
+/// Integrity warnings for a loaded run, for the caller to surface once.
+///
+/// Covers an announced discovery count that disagrees with the replayed mutants and artifact
+/// paths recorded as absolute, which stop resolving once the run directory moves.
+pub fn run_warnings(snapshot: &RunSnapshot) -> Vec<String> {
+    let run_id = &snapshot.run_id;
+    let mut warnings = Vec::new();
+    if let Some((announced, replayed)) = snapshot.discovery_mismatch() {
+        warnings.push(format!(
+            "run {run_id} announced {announced} discovered mutant(s) but its log replays {replayed}; the event log may be truncated"
+        ));
+    }
+    for (mutant_id, path) in absolute_artifact_paths(snapshot) {
+        warnings.push(format!(
+            "run {run_id} mutant {mutant_id} records absolute artifact path {path}; the run directory is not relocatable"
+        ));
+    }
+    warnings
+}
+
 /// Load run status snapshot.
+///
+/// Artifacts resolve relative to the run directory, so runs can be archived or moved between
+/// machines. Loading is silent; see [`run_warnings`] for integrity problems worth reporting.
 pub fn load_run_status(
     config: &MutationConfig,
     run_id: &str,
@@ -585,6 +925,14 @@ pub fn load_run_status(
     Ok(replay_events(&events)?)
 }
 
+/// Compact a run's event log in place, bounding its size for long resumable runs.
+///
+/// See [`compact_events`] for what the rewritten log contains; open runs are refused.
+pub fn compact_run(config: &MutationConfig, run_id: &str) -> Result<RunSnapshot, MutationRunError> {
+    let events = events_path(&config.run_root.join(run_id));
+    Ok(compact_events(&events)?)
+}
+
 /// Render run report.
 pub fn render_run_report(
     config: &MutationConfig,
@@ -595,6 +943,90 @@ pub fn render_run_report(
     Ok(render_report(&snapshot, format))
 }
 
+/// Package a run into a gzipped tarball at `out` for sharing or attaching to a bug.
+///
+/// The archive holds `events.jsonl`, a rendered `report.json`, and the stdout/stderr artifacts
+/// of every survivor, all under a `<run_id>/` prefix. It is written to a sibling `.partial` file
+/// and renamed onto `out` only once complete. Returns the archive entry names and the
+/// run-relative paths of survivor artifacts that were missing on disk and left out.
+pub fn bundle_run(
+    config: &MutationConfig,
+    run_id: &str,
+    out: &Path,
+) -> Result<(Vec<String>, Vec<String>), MutationRunError> {
+    let snapshot = load_run_status(config, run_id)?;
+    let mut partial = out.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+
+    match write_bundle(&config.run_root.join(run_id), &snapshot, &partial) {
+        Ok(bundled) => {
+            std::fs::rename(&partial, out)?;
+            Ok(bundled)
+        }
+        Err(err) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(err)
+        }
+    }
+}
+
+fn write_bundle(
+    run_dir: &Path,
+    snapshot: &RunSnapshot,
+    path: &Path,
+) -> Result<(Vec<String>, Vec<String>), MutationRunError> {
+    let prefix = Path::new(&snapshot.run_id);
+    let gz = GzEncoder::new(std::fs::File::create(path)?, Compression::default());
+    let mut archive = tar::Builder::new(gz);
+    let mut entries = Vec::new();
+    let mut missing = Vec::new();
+
+    let events_entry = prefix.join("events.jsonl");
+    archive.append_path_with_name(events_path(run_dir), &events_entry)?;
+    entries.push(events_entry.display().to_string());
+
+    let report = render_report(snapshot, ReportFormat::Json);
+    let report_entry = prefix.join("report.json");
+    let mut header = tar::Header::new_gnu();
+    header.set_size(report.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(now_timestamp_ms().max(0) as u64 / 1000);
+    header.set_cksum();
+    archive.append_data(&mut header, &report_entry, report.as_bytes())?;
+    entries.push(report_entry.display().to_string());
+
+    for state in snapshot.mutants.values() {
+        if state.status != MutationStatus::Survived {
+            continue;
+        }
+        for path in [&state.stdout_artifact_path, &state.stderr_artifact_path]
+            .into_iter()
+            .flatten()
+        {
+            let source = run_dir.join(path);
+            if !source.is_file() {
+                missing.push(path.clone());
+                continue;
+            }
+            // Legacy absolute paths are flattened under `artifacts/`.
+            let relative = Path::new(path);
+            let entry = if relative.is_absolute() {
+                prefix
+                    .join("artifacts")
+                    .join(relative.file_name().unwrap_or_default())
+            } else {
+                prefix.join(relative)
+            };
+            archive.append_path_with_name(&source, &entry)?;
+            entries.push(entry.display().to_string());
+        }
+    }
+
+    archive.into_inner()?.finish()?.sync_all()?;
+    Ok((entries, missing))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
@@ -618,6 +1050,7 @@ mod tests {
             selector: selector.to_string(),
             source_file: String::new(),
             source_line: 0,
+            function: None,
             mutation_type: MutationType::Unknown,
             original_code: String::new(),
             mutated_code: String::new(),
@@ -771,6 +1204,97 @@ mod tests {
         }
     }
 
+    /// Streams three mutants, tracing every hand-off and execution; optionally fails the listing
+    /// after `fail_after` deliveries.
+    #[derive(Clone)]
+    struct StreamingEngine {
+        trace: Arc<Mutex<Vec<String>>>,
+        fail_after: Option<usize>,
+        concurrent: bool,
+    }
+
+    impl StreamingEngine {
+        fn new(fail_after: Option<usize>) -> Self {
+            Self {
+                trace: Arc::new(Mutex::new(Vec::new())),
+                fail_after,
+                concurrent: true,
+            }
+        }
+
+        /// Behave like an in-place engine that must not execute while listing.
+        fn in_place(self) -> Self {
+            Self {
+                concurrent: false,
+                ..self
+            }
+        }
+
+        fn trace(&self) -> Vec<String> {
+            self.trace.lock().expect("trace mutex should lock").clone()
+        }
+    }
+
+    impl MutationEngine for StreamingEngine {
+        fn name(&self) -> &str {
+            "streaming"
+        }
+
+        fn discover_mutants(
+            &self,
+            _config: &MutationConfig,
+        ) -> Result<Vec<MutantSpec>, MutationEngineError> {
+            Ok(vec![
+                test_mutant("m1", "mutant-1", "sel1"),
+                test_mutant("m2", "mutant-2", "sel2"),
+                test_mutant("m3", "mutant-3", "sel3"),
+            ])
+        }
+
+        fn discover_mutants_streaming(
+            &self,
+            config: &MutationConfig,
+            on_mutant: &mut dyn FnMut(MutantSpec),
+        ) -> Result<usize, MutationEngineError> {
+            let mutants = self.discover_mutants(config)?;
+            let count = mutants.len();
+            for (delivered, mutant) in mutants.into_iter().enumerate() {
+                if self.fail_after == Some(delivered) {
+                    return Err(MutationEngineError::CommandFailed(
+                        "listing crashed".to_string(),
+                    ));
+                }
+                self.trace
+                    .lock()
+                    .expect("trace mutex should lock")
+                    .push(format!("discovered {}", mutant.id));
+                on_mutant(mutant);
+            }
+            Ok(count)
+        }
+
+        fn supports_concurrent_discovery(&self) -> bool {
+            self.concurrent
+        }
+
+        fn execute_mutant(
+            &self,
+            _config: &MutationConfig,
+            mutant: &MutantSpec,
+        ) -> Result<MutantExecutionResult, MutationEngineError> {
+            self.trace
+                .lock()
+                .expect("trace mutex should lock")
+                .push(format!("executed {}", mutant.id));
+            Ok(MutantExecutionResult {
+                outcome: MutationOutcome::Killed,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+            })
+        }
+    }
+
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     #[derive(Clone)]
     struct SlowEngine;
@@ -837,6 +1361,7 @@ mod tests {
                 run_id: run_id.to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 2,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -1018,6 +1543,7 @@ mod tests {
                 run_id: run_id.to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 3,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -1125,6 +1651,7 @@ mod tests {
                 run_id: run_id.to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 2,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -1252,6 +1779,94 @@ mod tests {
         assert_eq!(run.snapshot.pending_mutants().len(), 1);
     }
 
+    #[test]
+    fn run_new_executes_streamed_mutants_before_discovery_finishes() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+        let engine = StreamingEngine::new(None);
+
+        let run = run_new(&config, &engine).expect("streamed run should complete");
+        assert_eq!(
+            engine.trace(),
+            [
+                "discovered m1",
+                "executed m1",
+                "discovered m2",
+                "executed m2",
+                "discovered m3",
+                "executed m3"
+            ]
+        );
+        assert!(run.snapshot.completed);
+        assert_eq!(run.snapshot.info.discovered, Some(3));
+        assert_eq!(run.snapshot.discovery_mismatch(), None);
+
+        let filtered = run_new(
+            &config.clone().with_filter("mutant-2"),
+            &StreamingEngine::new(None),
+        )
+        .expect("filtered run should complete");
+        assert_eq!(filtered.snapshot.info.discovered, Some(1));
+        assert_eq!(filtered.snapshot.mutants.len(), 1);
+    }
+
+    #[test]
+    fn run_new_finishes_listing_before_in_place_execution() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+        let engine = StreamingEngine::new(None).in_place();
+
+        let run = run_new(&config, &engine).expect("listed run should complete");
+        assert_eq!(
+            engine.trace(),
+            [
+                "discovered m1",
+                "discovered m2",
+                "discovered m3",
+                "executed m1",
+                "executed m2",
+                "executed m3"
+            ]
+        );
+        assert!(run.snapshot.completed);
+        assert!(!run.snapshot.info.discovery_streaming);
+        assert_eq!(run.snapshot.info.discovered, Some(3));
+
+        let err = run_new(
+            &config.clone().with_run_root(tmp.path().join("failed")),
+            &StreamingEngine::new(Some(1)).in_place(),
+        )
+        .expect_err("a crashed listing should fail the run");
+        assert!(err.to_string().contains("listing crashed"));
+    }
+
+    #[test]
+    fn failed_streaming_discovery_is_finished_on_resume() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+
+        let err = run_new(&config, &StreamingEngine::new(Some(1)))
+            .expect_err("a crashed listing should fail the run");
+        assert!(err.to_string().contains("listing crashed"));
+
+        let engine = StreamingEngine::new(None);
+        let resumed = run_new(&config, &engine).expect("resume should finish discovery");
+        assert_eq!(std::fs::read_dir(tmp.path()).expect("run root").count(), 1);
+        assert!(resumed.snapshot.completed);
+        assert_eq!(resumed.snapshot.info.discovered, Some(3));
+        assert_eq!(engine.trace(), ["executed m2", "executed m3"]);
+        assert!(
+            resumed
+                .snapshot
+                .mutants
+                .values()
+                .all(|state| state.status == MutationStatus::Killed)
+        );
+    }
+
     #[test]
     fn run_new_resumes_latest_incomplete_run() {
         let _guard = test_guard();
@@ -1297,6 +1912,460 @@ mod tests {
         assert!(md.contains("| killed |"));
     }
 
+    #[test]
+    fn artifact_policy_controls_written_subset() {
+        let outcomes = [
+            ("killed", MutationOutcome::Killed),
+            ("survived", MutationOutcome::Survived),
+            ("timeout", MutationOutcome::Timeout),
+            (
+                "error",
+                MutationOutcome::Error {
+                    message: "boom".to_string(),
+                },
+            ),
+        ];
+        let cases = [
+            (
+                ArtifactPolicy::All,
+                vec!["killed", "survived", "timeout", "error"],
+            ),
+            (
+                ArtifactPolicy::FailuresOnly,
+                vec!["survived", "timeout", "error"],
+            ),
+            (
+                ArtifactPolicy::SurvivorsAndErrors,
+                vec!["survived", "error"],
+            ),
+            (ArtifactPolicy::None, vec![]),
+        ];
+
+        for (policy, expected) in cases {
+            let tmp = tempdir().expect("tempdir should be created");
+            let mut written = Vec::new();
+            for (id, outcome) in &outcomes {
+                let result = MutantExecutionResult {
+                    outcome: outcome.clone(),
+                    exit_code: None,
+                    stdout: "out".to_string(),
+                    stderr: "err".to_string(),
+                };
+                let (stdout, stderr) = write_mutant_artifacts(tmp.path(), id, &result, policy)
+                    .expect("artifact write should succeed");
+                assert_eq!(stdout.is_some(), stderr.is_some());
+                if let Some(path) = stdout {
+                    assert!(tmp.path().join(path).is_file());
+                    written.push(*id);
+                }
+            }
+            assert_eq!(written, expected, "unexpected artifacts for {policy:?}");
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn snapshot_compatibility_is_reflexive(
+            config in crate::generators::mutation::mutation_config_strategy()
+        ) {
+            let snapshot = RunSnapshot {
+                run_id: "run-reflexive".to_string(),
+                mutants: std::collections::BTreeMap::new(),
+                malformed_lines: 0,
+                interrupted: true,
+                completed: false,
+                info: crate::mutation::state::RunInfo {
+                    config: Some(config_snapshot(&config, &FakeEngine)),
+                    metadata: None,
+                    discovered: Some(0),
+                    discovery_streaming: false,
+                    interruption_reason: Some("signal".to_string()),
+                    closed: true,
+                },
+                started_at_ms: None,
+                ended_at_ms: None,
+            };
+            proptest::prop_assert!(is_snapshot_compatible(&snapshot, &config, &FakeEngine));
+        }
+    }
+
+    #[derive(Clone)]
+    struct RenamedEngine;
+
+    impl MutationEngine for RenamedEngine {
+        fn name(&self) -> &str {
+            "renamed-engine"
+        }
+
+        fn discover_mutants(
+            &self,
+            config: &MutationConfig,
+        ) -> Result<Vec<MutantSpec>, MutationEngineError> {
+            FakeEngine.discover_mutants(config)
+        }
+
+        fn execute_mutant(
+            &self,
+            config: &MutationConfig,
+            mutant: &MutantSpec,
+        ) -> Result<MutantExecutionResult, MutationEngineError> {
+            FakeEngine.execute_mutant(config, mutant)
+        }
+    }
+
+    #[test]
+    fn engine_change_makes_snapshot_incompatible() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+
+        let interrupted = run_new(&config, &InterruptingEngine)
+            .expect("run should capture interruption and leave pending mutants");
+        assert_eq!(
+            interrupted
+                .snapshot
+                .info
+                .config
+                .as_ref()
+                .and_then(|cfg| cfg.engine.as_deref()),
+            Some(InterruptingEngine.name())
+        );
+        assert!(is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config,
+            &InterruptingEngine
+        ));
+        assert!(!is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config,
+            &RenamedEngine
+        ));
+
+        let fresh = run_new(&config, &RenamedEngine)
+            .expect("run under a different engine should start fresh");
+        assert_ne!(fresh.run_id, interrupted.run_id);
+    }
+
+    #[test]
+    fn package_change_makes_snapshot_incompatible() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default()
+            .with_run_root(tmp.path())
+            .with_package("core");
+
+        let interrupted = run_new(&config, &InterruptingEngine)
+            .expect("run should capture interruption and leave pending mutants");
+        assert!(is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config,
+            &InterruptingEngine
+        ));
+        assert!(!is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config.clone().with_package("cli"),
+            &InterruptingEngine
+        ));
+    }
+
+    #[test]
+    fn pass_through_args_change_makes_snapshot_incompatible() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default()
+            .with_run_root(tmp.path())
+            .with_extra_args(vec!["--features".to_string(), "fast".to_string()]);
+
+        let interrupted = run_new(&config, &InterruptingEngine)
+            .expect("run should capture interruption and leave pending mutants");
+        assert!(is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config,
+            &InterruptingEngine
+        ));
+        assert!(!is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config
+                .clone()
+                .with_extra_args(vec!["--all-features".to_string()]),
+            &InterruptingEngine
+        ));
+        assert!(!is_snapshot_compatible(
+            &interrupted.snapshot,
+            &config
+                .clone()
+                .with_test_args(vec!["--test-threads=1".to_string()]),
+            &InterruptingEngine
+        ));
+
+        let fresh = run_new(
+            &config
+                .clone()
+                .with_test_args(vec!["--test-threads=1".to_string()]),
+            &InterruptingEngine,
+        )
+        .expect("run with different test args should start fresh");
+        assert_ne!(fresh.run_id, interrupted.run_id);
+    }
+
+    #[test]
+    fn clean_run_is_not_reused_across_pass_through_args() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default()
+            .with_run_root(tmp.path())
+            .with_reuse_clean_runs(true);
+
+        let first = run_new(&config, &AlwaysKilledEngine).expect("first run should complete");
+        let other = run_new(
+            &config
+                .clone()
+                .with_extra_args(vec!["--all-features".to_string()]),
+            &AlwaysKilledEngine,
+        )
+        .expect("run with different args should complete");
+        assert_ne!(other.run_id, first.run_id);
+    }
+
+    #[test]
+    fn rerun_after_clean_run_is_a_no_op_when_enabled() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default()
+            .with_run_root(tmp.path())
+            .with_reuse_clean_runs(true);
+        let run_dirs = || std::fs::read_dir(tmp.path()).expect("run root").count();
+
+        let first = run_new(&config, &AlwaysKilledEngine).expect("first run should complete");
+        assert!(first.snapshot.completed);
+        assert_eq!(run_dirs(), 1);
+
+        let second = run_new(&config, &AlwaysKilledEngine).expect("rerun should short-circuit");
+        assert_eq!(second.run_id, first.run_id);
+        assert_eq!(second.snapshot.mutants.len(), 2);
+        assert_eq!(run_dirs(), 1);
+
+        let fresh = run_new(
+            &config.clone().with_reuse_clean_runs(false),
+            &AlwaysKilledEngine,
+        )
+        .expect("run without the flag should start fresh");
+        assert_ne!(fresh.run_id, first.run_id);
+        assert_eq!(run_dirs(), 2);
+    }
+
+    #[derive(Clone)]
+    struct NoisyEngine;
+
+    impl MutationEngine for NoisyEngine {
+        fn discover_mutants(
+            &self,
+            config: &MutationConfig,
+        ) -> Result<Vec<MutantSpec>, MutationEngineError> {
+            FakeEngine.discover_mutants(config)
+        }
+
+        fn execute_mutant(
+            &self,
+            config: &MutationConfig,
+            mutant: &MutantSpec,
+        ) -> Result<MutantExecutionResult, MutationEngineError> {
+            let mut result = FakeEngine.execute_mutant(config, mutant)?;
+            result.stdout = format!("test output for {}", mutant.id);
+            Ok(result)
+        }
+    }
+
+    #[test]
+    fn bundle_contains_events_report_and_survivor_artifacts() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path().join("runs"));
+        let run = run_new(&config, &NoisyEngine).expect("run should complete");
+        let out = tmp.path().join("bundle.tar.gz");
+
+        let (entries, missing) =
+            bundle_run(&config, &run.run_id, &out).expect("bundle should be written");
+        assert!(missing.is_empty());
+        assert!(!tmp.path().join("bundle.tar.gz.partial").exists());
+
+        let file = std::fs::File::open(&out).expect("bundle should exist");
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+        let mut names: Vec<String> = archive
+            .entries()
+            .expect("archive should be readable")
+            .map(|entry| {
+                let entry = entry.expect("entry should be readable");
+                entry.path().expect("entry path").display().to_string()
+            })
+            .collect();
+        names.sort();
+        let mut expected = vec![
+            format!("{}/artifacts/m2.stdout.log", run.run_id),
+            format!("{}/events.jsonl", run.run_id),
+            format!("{}/report.json", run.run_id),
+        ];
+        expected.sort();
+        assert_eq!(names, expected, "killed mutant m1 must not be bundled");
+
+        let mut sorted_entries = entries;
+        sorted_entries.sort();
+        assert_eq!(sorted_entries, expected);
+
+        std::fs::remove_file(run.run_dir.join("artifacts/m2.stdout.log"))
+            .expect("artifact should be removed");
+        let (entries, missing) =
+            bundle_run(&config, &run.run_id, &out).expect("bundle should still be written");
+        assert_eq!(missing, ["artifacts/m2.stdout.log"]);
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn dry_run_skips_every_mutant_and_still_reports() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+
+        let run = run_new(&config, &crate::mutation::DryRunEngine::new(FakeEngine))
+            .expect("dry run should complete");
+        assert!(run.snapshot.completed);
+        assert_eq!(run.snapshot.mutants.len(), 2);
+        assert!(
+            run.snapshot
+                .mutants
+                .values()
+                .all(|state| state.status == MutationStatus::Skipped)
+        );
+
+        let json: serde_json::Value = serde_json::from_str(
+            &render_run_report(&config, &run.run_id, ReportFormat::Json)
+                .expect("report should render"),
+        )
+        .expect("dry-run report should be valid json");
+        assert_eq!(json["summary"]["skipped"], 2);
+        assert_eq!(json["summary"]["total"], 2);
+
+        // A later real run must not resume or reuse the dry run.
+        let real = run_new(&config, &FakeEngine).expect("real run should start fresh");
+        assert_ne!(real.run_id, run.run_id);
+    }
+
+    #[test]
+    fn run_and_summarize_matches_snapshot() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+
+        let (run, summary) = run_and_summarize(&config, &FakeEngine).expect("run should complete");
+        assert_eq!(summary, RunSummary::from_snapshot(&run.snapshot));
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.killed, 1);
+        assert_eq!(summary.survived, 1);
+        assert_eq!(summary.incomplete, 0);
+    }
+
+    #[test]
+    fn type_rules_reclassify_discovered_mutants() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default()
+            .with_run_root(tmp.path())
+            .with_type_rules(vec![(
+                regex::Regex::new("^mutant-2$").expect("valid regex"),
+                MutationType::Boolean,
+            )]);
+
+        let run = run_new(&config, &FakeEngine).expect("run should complete");
+        let type_of = |id: &str| run.snapshot.mutants[id].spec.mutation_type;
+        assert_eq!(type_of("m1"), MutationType::Unknown);
+        assert_eq!(type_of("m2"), MutationType::Boolean);
+    }
+
+    #[test]
+    fn compact_run_preserves_resumable_state() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+
+        let interrupted = run_new(&config, &InterruptingEngine)
+            .expect("run should capture interruption and leave pending mutants");
+        let compacted =
+            compact_run(&config, &interrupted.run_id).expect("compaction should succeed");
+        assert_eq!(compacted.mutants, interrupted.snapshot.mutants);
+        assert_eq!(
+            latest_incomplete_run_id(&config, &InterruptingEngine)
+                .expect("scan should succeed")
+                .as_deref(),
+            Some(interrupted.run_id.as_str())
+        );
+    }
+
+    #[test]
+    fn replay_engine_reproduces_recorded_statuses() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let source_config = MutationConfig::default().with_run_root(tmp.path().join("source"));
+        let source = run_new(&source_config, &FakeEngine).expect("source run should complete");
+
+        let engine = crate::mutation::ReplayEngine::from_events(&events_path(&source.run_dir))
+            .expect("replay engine should load");
+        let replay_config = MutationConfig::default().with_run_root(tmp.path().join("replay"));
+        let replayed = run_new(&replay_config, &engine).expect("replayed run should complete");
+
+        assert!(replayed.snapshot.completed);
+        let statuses =
+            |snapshot: &RunSnapshot| -> Vec<(String, crate::mutation::state::MutationStatus)> {
+                snapshot
+                    .mutants
+                    .values()
+                    .map(|m| (m.spec.id.clone(), m.status.clone()))
+                    .collect()
+            };
+        assert_eq!(statuses(&replayed.snapshot), statuses(&source.snapshot));
+    }
+
+    #[test]
+    fn relocated_run_directory_still_loads_with_artifacts() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let original_root = tmp.path().join("machine-a");
+        let config = MutationConfig::default().with_run_root(&original_root);
+        let run = run_new(&config, &ErrorEngine).expect("run should complete");
+
+        let moved_root = tmp.path().join("archive").join("machine-b");
+        std::fs::create_dir_all(moved_root.parent().expect("parent")).expect("archive dir");
+        std::fs::rename(&original_root, &moved_root).expect("run root should move");
+
+        let moved_config = MutationConfig::default().with_run_root(&moved_root);
+        let snapshot =
+            load_run_status(&moved_config, &run.run_id).expect("relocated run should load");
+        assert_eq!(snapshot.mutants, run.snapshot.mutants);
+        assert!(absolute_artifact_paths(&snapshot).is_empty());
+        crate::mutation::assert_artifacts_present(&snapshot, &moved_root.join(&run.run_id));
+    }
+
+    #[test]
+    fn absolute_artifact_paths_are_detected() {
+        let _guard = test_guard();
+        let tmp = tempdir().expect("tempdir should be created");
+        let config = MutationConfig::default().with_run_root(tmp.path());
+        let mut run = run_new(&config, &ErrorEngine).expect("run should complete");
+        let state = run
+            .snapshot
+            .mutants
+            .get_mut("m_err")
+            .expect("error mutant should be tracked");
+        state.stdout_artifact_path = Some("/var/tmp/m_err.stdout.log".to_string());
+
+        assert_eq!(
+            absolute_artifact_paths(&run.snapshot),
+            vec![("m_err".to_string(), "/var/tmp/m_err.stdout.log".to_string())]
+        );
+        let warnings = run_warnings(&run.snapshot);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("mutant m_err records absolute artifact path"));
+    }
+
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     #[test]
     fn run_handles_real_sigint_signal() {
@@ -4,11 +4,13 @@ use std::collections::BTreeMap;
 use std::fs::OpenOptions;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::str::FromStr;
 
 use thiserror::Error;
 
 use super::events::{
-    MutantSpec, MutationEvent, MutationOutcome, RunConfigSnapshot, RunMetadata, TestFailure,
+    MutantSpec, MutationEvent, MutationOutcome, ParseStatusError, RunConfigSnapshot, RunMetadata,
+    TestFailure, now_timestamp_ms,
 };
 
 /// Status derived from event stream for each mutant.
@@ -47,6 +49,39 @@ impl MutationStatus {
     }
 }
 
+impl std::fmt::Display for MutationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Running => write!(f, "running"),
+            Self::Killed => write!(f, "killed"),
+            Self::Survived => write!(f, "survived"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::Unviable => write!(f, "unviable"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FromStr for MutationStatus {
+    type Err = ParseStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "killed" => Ok(Self::Killed),
+            "survived" => Ok(Self::Survived),
+            "timeout" => Ok(Self::Timeout),
+            "unviable" => Ok(Self::Unviable),
+            "skipped" => Ok(Self::Skipped),
+            "error" => Ok(Self::Error),
+            _ => Err(ParseStatusError(s.to_string())),
+        }
+    }
+}
+
 /// Per-mutant state in replay snapshot.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MutantState {
@@ -76,19 +111,31 @@ pub struct MutantState {
     pub stdout_preview: Option<String>,
     /// Preview of stderr output.
     pub stderr_preview: Option<String>,
+    /// Terminal status of the previous attempt while a rerun is in flight.
+    pub previous_status: Option<MutationStatus>,
 }
 
 /// Run-level metadata from RunStarted event.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct RunInfo {
     /// Configuration snapshot.
     pub config: Option<RunConfigSnapshot>,
     /// Environment metadata.
     pub metadata: Option<RunMetadata>,
+    /// Mutant count announced by `RunStarted`, or by `RunCompleted` for streamed discovery;
+    /// `None` while a streamed discovery is unfinished.
+    pub discovered: Option<usize>,
+    /// Whether mutants were announced while the listing was still running.
+    pub discovery_streaming: bool,
+    /// Reason given by the latest `RunInterrupted`.
+    pub interruption_reason: Option<String>,
+    /// Whether the latest run marker is `RunInterrupted` or `RunCompleted`, i.e. no run is
+    /// appending to the log.
+    pub closed: bool,
 }
 
 /// Materialized run state derived from `events.jsonl`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RunSnapshot {
     /// Run id.
     pub run_id: String,
@@ -102,9 +149,28 @@ pub struct RunSnapshot {
     pub completed: bool,
     /// Run-level info (config, metadata).
     pub info: RunInfo,
+    /// Earliest event timestamp in the log.
+    pub started_at_ms: Option<i64>,
+    /// Latest event timestamp in the log.
+    pub ended_at_ms: Option<i64>,
 }
 
 impl RunSnapshot {
+    /// Wall-clock span between the first and last recorded events.
+    pub fn duration_ms(&self) -> Option<u64> {
+        self.started_at_ms
+            .zip(self.ended_at_ms)
+            .and_then(|(start, end)| u64::try_from(end.checked_sub(start)?).ok())
+    }
+
+    /// `(announced, replayed)` when the announced discovery count disagrees with the mutants replayed.
+    ///
+    /// A mismatch means discovery events were lost, e.g. a truncated or hand-edited log.
+    pub fn discovery_mismatch(&self) -> Option<(usize, usize)> {
+        let announced = self.info.discovered?;
+        (announced != self.mutants.len()).then_some((announced, self.mutants.len()))
+    }
+
     /// Collect remaining mutants to execute/re-execute.
     pub fn pending_mutants(&self) -> Vec<MutantSpec> {
         self.mutants
@@ -130,6 +196,9 @@ pub enum MutationStateError {
     /// IO failure.
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
+    /// The run has not been interrupted or completed, so its log may still be appended to.
+    #[error("run {0} is still open; only interrupted or completed runs can be compacted")]
+    RunOpen(String),
 }
 
 /// Replay event log from `events.jsonl` into a snapshot.
@@ -137,30 +206,54 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
     let file = std::fs::File::open(events_path)?;
     let reader = BufReader::new(file);
 
+    let lines = reader.lines().filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(Ok(serde_json::from_str::<MutationEvent>(&line).ok())),
+        Err(err) => Some(Err(err)),
+    });
+    project_events(lines)
+}
+
+/// Replay in-memory events into a snapshot, exactly as [`replay_events`] would from disk.
+pub fn replay_event_list(events: &[MutationEvent]) -> RunSnapshot {
+    match project_events(events.iter().cloned().map(|event| Ok(Some(event)))) {
+        Ok(snapshot) => snapshot,
+        Err(err) => unreachable!("in-memory replay cannot fail: {err}"),
+    }
+}
+
+/// Fold parsed events (`None` marks a malformed line) into a snapshot.
+fn project_events<I>(events: I) -> Result<RunSnapshot, MutationStateError>
+where
+    I: IntoIterator<Item = std::io::Result<Option<MutationEvent>>>,
+{
     let mut run_id = String::new();
     let mut mutants: BTreeMap<String, MutantState> = BTreeMap::new();
     let mut malformed_lines = 0;
     let mut interrupted = false;
     let mut completed = false;
     let mut info = RunInfo::default();
+    let mut started_at_ms: Option<i64> = None;
+    let mut ended_at_ms: Option<i64> = None;
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        let event = match serde_json::from_str::<MutationEvent>(&line) {
-            Ok(event) => event,
-            Err(_) => {
+    for event in events {
+        let event = match event? {
+            Some(event) => event,
+            None => {
                 malformed_lines += 1;
                 continue;
             }
         };
 
+        let timestamp_ms = event.timestamp_ms();
+        started_at_ms = Some(started_at_ms.map_or(timestamp_ms, |ts| ts.min(timestamp_ms)));
+        ended_at_ms = Some(ended_at_ms.map_or(timestamp_ms, |ts| ts.max(timestamp_ms)));
+
         match event {
             MutationEvent::RunStarted {
                 run_id: id,
+                discovered,
+                discovery_streaming,
                 config,
                 metadata,
                 ..
@@ -170,11 +263,15 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
                 }
                 info.config = config;
                 info.metadata = metadata;
+                info.discovered = (!discovery_streaming).then_some(discovered);
+                info.discovery_streaming = discovery_streaming;
+                info.closed = false;
             }
             MutationEvent::RunResumed { run_id: id, .. } => {
                 if run_id.is_empty() {
                     run_id = id;
                 }
+                info.closed = false;
             }
             MutationEvent::MutantDiscovered { mutant, .. } => {
                 mutants.insert(
@@ -193,6 +290,7 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
                         tests_failed: Vec::new(),
                         stdout_preview: None,
                         stderr_preview: None,
+                        previous_status: None,
                     },
                 );
             }
@@ -202,6 +300,9 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
                 ..
             } => {
                 if let Some(state) = mutants.get_mut(&mutant_id) {
+                    if state.status.is_terminal() {
+                        state.previous_status = Some(state.status.clone());
+                    }
                     state.status = MutationStatus::Running;
                     state.started_at_ms = Some(timestamp_ms);
                 }
@@ -242,6 +343,7 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
                     state.tests_failed = tests_failed;
                     state.stdout_preview = stdout_preview;
                     state.stderr_preview = stderr_preview;
+                    state.previous_status = None;
                     match outcome {
                         MutationOutcome::Killed => state.status = MutationStatus::Killed,
                         MutationOutcome::Survived => state.status = MutationStatus::Survived,
@@ -255,11 +357,17 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
                     }
                 }
             }
-            MutationEvent::RunInterrupted { .. } => {
+            MutationEvent::RunInterrupted { reason, .. } => {
                 interrupted = true;
+                info.interruption_reason = Some(reason);
+                info.closed = true;
             }
-            MutationEvent::RunCompleted { .. } => {
+            MutationEvent::RunCompleted { discovered, .. } => {
                 completed = true;
+                info.closed = true;
+                if discovered.is_some() {
+                    info.discovered = discovered;
+                }
             }
         }
     }
@@ -271,9 +379,163 @@ pub fn replay_events(events_path: &Path) -> Result<RunSnapshot, MutationStateErr
         interrupted,
         completed,
         info,
+        started_at_ms,
+        ended_at_ms,
     })
 }
 
+/// Rewrite `events_path` as the minimal event sequence that replays to the same snapshot.
+///
+/// The compacted log keeps run markers, one `MutantDiscovered` per mutant and the events needed
+/// to reproduce each mutant's final state; malformed lines are dropped. The log is replaced
+/// atomically via a sibling temporary file. Returns the replayed compacted snapshot.
+///
+/// Appends racing the rewrite would be lost, so runs whose latest marker is not
+/// `RunInterrupted` or `RunCompleted` are refused with [`MutationStateError::RunOpen`].
+pub fn compact_events(events_path: &Path) -> Result<RunSnapshot, MutationStateError> {
+    let snapshot = replay_events(events_path)?;
+    if !snapshot.info.closed {
+        return Err(MutationStateError::RunOpen(snapshot.run_id));
+    }
+
+    let mut out = String::new();
+    for event in compacted_events(&snapshot) {
+        out.push_str(&serde_json::to_string(&event).expect("mutation events should serialize"));
+        out.push('\n');
+    }
+
+    let tmp_path = events_path.with_extension("jsonl.compact");
+    {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(out.as_bytes())?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, events_path)?;
+
+    replay_events(events_path)
+}
+
+fn compacted_events(snapshot: &RunSnapshot) -> Vec<MutationEvent> {
+    let run_id = snapshot.run_id.clone();
+    let start = snapshot.started_at_ms.unwrap_or_else(now_timestamp_ms);
+    let end = snapshot.ended_at_ms.unwrap_or(start);
+
+    // A streamed discovery keeps its count on `RunCompleted`, so an unfinished one stays
+    // unfinished and resume can complete it.
+    let discovery_streaming = snapshot.info.discovery_streaming;
+    let mut events = vec![MutationEvent::RunStarted {
+        run_id: run_id.clone(),
+        timestamp_ms: start,
+        discovered: if discovery_streaming {
+            0
+        } else {
+            snapshot.mutants.len()
+        },
+        discovery_streaming,
+        config: snapshot.info.config.clone(),
+        metadata: snapshot.info.metadata.clone(),
+    }];
+    events.extend(
+        snapshot
+            .mutants
+            .values()
+            .map(|state| MutationEvent::MutantDiscovered {
+                run_id: run_id.clone(),
+                timestamp_ms: start,
+                mutant: state.spec.clone(),
+            }),
+    );
+    for state in snapshot.mutants.values() {
+        events.extend(compacted_mutant_events(&run_id, state, end));
+    }
+
+    if snapshot.interrupted {
+        events.push(MutationEvent::RunInterrupted {
+            run_id: run_id.clone(),
+            timestamp_ms: end,
+            reason: snapshot
+                .info
+                .interruption_reason
+                .clone()
+                .unwrap_or_default(),
+        });
+    }
+    if snapshot.completed {
+        events.push(MutationEvent::RunCompleted {
+            run_id,
+            timestamp_ms: end,
+            discovered: snapshot.info.discovered.filter(|_| discovery_streaming),
+        });
+    }
+
+    events
+}
+
+fn compacted_mutant_events(run_id: &str, state: &MutantState, end: i64) -> Vec<MutationEvent> {
+    let finished = |outcome: MutationOutcome| MutationEvent::MutantFinished {
+        run_id: run_id.to_string(),
+        timestamp_ms: state.finished_at_ms.unwrap_or(end),
+        mutant_id: state.spec.id.clone(),
+        outcome,
+        exit_code: state.exit_code,
+        stdout_artifact_path: state.stdout_artifact_path.clone(),
+        stderr_artifact_path: state.stderr_artifact_path.clone(),
+        started_at_ms: state.started_at_ms,
+        finished_at_ms: state.finished_at_ms,
+        duration_ms: state.duration_ms,
+        tests_run: state.tests_run.clone(),
+        tests_failed: state.tests_failed.clone(),
+        stdout_preview: state.stdout_preview.clone(),
+        stderr_preview: state.stderr_preview.clone(),
+    };
+
+    let mut events = Vec::new();
+    // Replay keeps an earlier error message across later reruns, so restate it first.
+    if let Some(message) = &state.last_error {
+        if state.status != MutationStatus::Error {
+            events.push(finished(MutationOutcome::Error {
+                message: message.clone(),
+            }));
+        }
+    }
+
+    match state.status.clone() {
+        MutationStatus::Running => {
+            // A rerun in flight still carries the previous attempt's results; an errored attempt
+            // was already restated above.
+            let previous = state
+                .previous_status
+                .clone()
+                .filter(|status| *status != MutationStatus::Error)
+                .and_then(|status| compacted_outcome(state, status));
+            events.extend(previous.map(finished));
+            events.push(MutationEvent::MutantStarted {
+                run_id: run_id.to_string(),
+                timestamp_ms: state.started_at_ms.unwrap_or(end),
+                mutant_id: state.spec.id.clone(),
+            });
+        }
+        status => events.extend(compacted_outcome(state, status).map(finished)),
+    }
+
+    events
+}
+
+/// Outcome that replays to the terminal `status`; `None` for pending and running mutants.
+fn compacted_outcome(state: &MutantState, status: MutationStatus) -> Option<MutationOutcome> {
+    match status {
+        MutationStatus::Killed => Some(MutationOutcome::Killed),
+        MutationStatus::Survived => Some(MutationOutcome::Survived),
+        MutationStatus::Timeout => Some(MutationOutcome::Timeout),
+        MutationStatus::Unviable => Some(MutationOutcome::Unviable),
+        MutationStatus::Skipped => Some(MutationOutcome::Skipped),
+        MutationStatus::Error => Some(MutationOutcome::Error {
+            message: state.last_error.clone().unwrap_or_default(),
+        }),
+        MutationStatus::Pending | MutationStatus::Running => None,
+    }
+}
+
 /// Append one event as JSONL line with fsync for durability.
 pub fn append_event(events_path: &Path, event: &MutationEvent) -> Result<(), MutationStateError> {
     let mut file = OpenOptions::new()
@@ -293,7 +555,47 @@ mod tests {
     use tempfile::tempdir;
 
     use super::*;
-    use crate::mutation::events::{MutationType, now_timestamp_ms};
+    use crate::mutation::events::MutationType;
+
+    #[test]
+    fn status_strings_roundtrip_for_every_variant() {
+        for status in [
+            MutationStatus::Pending,
+            MutationStatus::Running,
+            MutationStatus::Killed,
+            MutationStatus::Survived,
+            MutationStatus::Timeout,
+            MutationStatus::Unviable,
+            MutationStatus::Skipped,
+            MutationStatus::Error,
+        ] {
+            assert_eq!(status.to_string().parse::<MutationStatus>(), Ok(status));
+        }
+        assert_eq!(
+            "Killed".parse::<MutationStatus>(),
+            Err(ParseStatusError("Killed".to_string()))
+        );
+    }
+
+    #[test]
+    fn outcome_strings_roundtrip_for_every_variant() {
+        for outcome in [
+            MutationOutcome::Killed,
+            MutationOutcome::Survived,
+            MutationOutcome::Timeout,
+            MutationOutcome::Unviable,
+            MutationOutcome::Skipped,
+            MutationOutcome::Error {
+                message: String::new(),
+            },
+            MutationOutcome::Error {
+                message: "rustc crashed: exit 101".to_string(),
+            },
+        ] {
+            assert_eq!(outcome.to_string().parse::<MutationOutcome>(), Ok(outcome));
+        }
+        assert!("pending".parse::<MutationOutcome>().is_err());
+    }
 
     fn test_mutant(id: &str, label: &str, selector: &str) -> MutantSpec {
         MutantSpec {
@@ -302,6 +604,7 @@ mod tests {
             selector: selector.to_string(),
             source_file: String::new(),
             source_line: 0,
+            function: None,
             mutation_type: MutationType::Unknown,
             original_code: String::new(),
             mutated_code: String::new(),
@@ -321,6 +624,7 @@ mod tests {
                 run_id: "run-1".to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 1,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -342,6 +646,50 @@ mod tests {
         assert_eq!(a.mutants.len(), b.mutants.len());
     }
 
+    #[test]
+    fn missing_discovery_event_is_flagged() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let events_path = tmp.path().join("events.jsonl");
+
+        append_event(
+            &events_path,
+            &MutationEvent::RunStarted {
+                run_id: "run-1".to_string(),
+                timestamp_ms: now_timestamp_ms(),
+                discovered: 2,
+                discovery_streaming: false,
+                config: None,
+                metadata: None,
+            },
+        )
+        .expect("run started should append");
+        append_event(
+            &events_path,
+            &MutationEvent::MutantDiscovered {
+                run_id: "run-1".to_string(),
+                timestamp_ms: now_timestamp_ms(),
+                mutant: test_mutant("m1", "mutant 1", "sel1"),
+            },
+        )
+        .expect("mutant discovered should append");
+
+        let truncated = replay_events(&events_path).expect("replay should work");
+        assert_eq!(truncated.info.discovered, Some(2));
+        assert_eq!(truncated.discovery_mismatch(), Some((2, 1)));
+
+        append_event(
+            &events_path,
+            &MutationEvent::MutantDiscovered {
+                run_id: "run-1".to_string(),
+                timestamp_ms: now_timestamp_ms(),
+                mutant: test_mutant("m2", "mutant 2", "sel2"),
+            },
+        )
+        .expect("mutant discovered should append");
+        let complete = replay_events(&events_path).expect("replay should work");
+        assert_eq!(complete.discovery_mismatch(), None);
+    }
+
     #[test]
     fn malformed_tail_is_ignored() {
         let tmp = tempdir().expect("tempdir should be created");
@@ -353,6 +701,7 @@ mod tests {
                 run_id: "run-1".to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 0,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -386,6 +735,7 @@ mod tests {
                 run_id: "run-2".to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 3,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -455,6 +805,7 @@ mod tests {
                 run_id: "run-3".to_string(),
                 timestamp_ms: now_timestamp_ms(),
                 discovered: 1,
+                discovery_streaming: false,
                 config: None,
                 metadata: None,
             },
@@ -500,4 +851,130 @@ mod tests {
         assert_eq!(state.status, MutationStatus::Error);
         assert_eq!(state.last_error.as_deref(), Some("boom"));
     }
+
+    #[test]
+    fn compacted_log_replays_to_equivalent_snapshot() {
+        let tmp = tempdir().expect("tempdir should be created");
+        let events_path = tmp.path().join("events.jsonl");
+        let run_id = "run-compact".to_string();
+        let finished =
+            |ts: i64, id: &str, outcome: MutationOutcome| MutationEvent::MutantFinished {
+                run_id: run_id.clone(),
+                timestamp_ms: ts,
+                mutant_id: id.to_string(),
+                outcome,
+                exit_code: Some(1),
+                stdout_artifact_path: Some(format!("artifacts/{id}.stdout.log")),
+                stderr_artifact_path: None,
+                started_at_ms: None,
+                finished_at_ms: None,
+                duration_ms: None,
+                tests_run: vec!["tests::it".to_string()],
+                tests_failed: Vec::new(),
+                stdout_preview: Some("preview".to_string()),
+                stderr_preview: None,
+            };
+        let started = |ts: i64, id: &str| MutationEvent::MutantStarted {
+            run_id: run_id.clone(),
+            timestamp_ms: ts,
+            mutant_id: id.to_string(),
+        };
+
+        let mut events = vec![MutationEvent::RunStarted {
+            run_id: run_id.clone(),
+            timestamp_ms: 100,
+            discovered: 4,
+            discovery_streaming: false,
+            config: None,
+            metadata: None,
+        }];
+        for id in ["m1", "m2", "m3", "m4"] {
+            events.push(MutationEvent::MutantDiscovered {
+                run_id: run_id.clone(),
+                timestamp_ms: 110,
+                mutant: test_mutant(id, id, id),
+            });
+        }
+        events.extend([
+            started(200, "m1"),
+            finished(260, "m1", MutationOutcome::Killed),
+            started(270, "m4"),
+            finished(280, "m4", MutationOutcome::Survived),
+            started(300, "m2"),
+            finished(
+                350,
+                "m2",
+                MutationOutcome::Error {
+                    message: "flaky".to_string(),
+                },
+            ),
+            MutationEvent::RunInterrupted {
+                run_id: run_id.clone(),
+                timestamp_ms: 400,
+                reason: "signal".to_string(),
+            },
+            MutationEvent::RunResumed {
+                run_id: run_id.clone(),
+                timestamp_ms: 500,
+                remaining: 3,
+            },
+            started(510, "m2"),
+            finished(590, "m2", MutationOutcome::Survived),
+            started(600, "m3"),
+            started(610, "m4"),
+        ]);
+        for event in &events {
+            append_event(&events_path, event).expect("event should append");
+        }
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&events_path)
+            .expect("events file should open");
+        file.write_all(b"{bad json\n")
+            .expect("malformed line should write");
+
+        let err = compact_events(&events_path).expect_err("an open run should not be compacted");
+        assert!(matches!(err, MutationStateError::RunOpen(id) if id == run_id));
+
+        let interrupt = MutationEvent::RunInterrupted {
+            run_id: run_id.clone(),
+            timestamp_ms: 700,
+            reason: "worker crashed".to_string(),
+        };
+        append_event(&events_path, &interrupt).expect("interrupt should append");
+        events.push(interrupt);
+
+        let before = replay_events(&events_path).expect("original log should replay");
+        let after = compact_events(&events_path).expect("compaction should succeed");
+
+        assert_eq!(after.run_id, before.run_id);
+        assert_eq!(after.mutants, before.mutants);
+        assert_eq!(after.interrupted, before.interrupted);
+        assert_eq!(after.completed, before.completed);
+        assert_eq!(after.started_at_ms, before.started_at_ms);
+        assert_eq!(after.ended_at_ms, before.ended_at_ms);
+        assert_eq!(after.info, before.info);
+        assert_eq!(
+            after.info.interruption_reason.as_deref(),
+            Some("worker crashed")
+        );
+        assert_eq!(after.malformed_lines, 0);
+        assert_eq!(
+            after.mutants["m4"].previous_status,
+            Some(MutationStatus::Survived),
+            "a rerun in flight should keep its previous outcome"
+        );
+        assert_eq!(
+            after.mutants["m2"].last_error.as_deref(),
+            Some("flaky"),
+            "stale error message should survive compaction"
+        );
+
+        let compacted_lines = std::fs::read_to_string(&events_path)
+            .expect("compacted log should be readable")
+            .lines()
+            .count();
+        assert!(compacted_lines < events.len() + 1);
+        assert!(!events_path.with_extension("jsonl.compact").exists());
+    }
 }
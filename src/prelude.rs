@@ -2,23 +2,50 @@
 
 pub use proptest::prelude::*;
 
+pub use crate::bench::sample_n;
+pub use crate::config::pbt_config;
+pub use crate::corpus::from_corpus;
 pub use crate::generators::collections::{unique_vec, vec_of};
-pub use crate::generators::decimals::tick_aligned;
+pub use crate::generators::decimals::{extreme_price, tick_aligned, tick_edge_prices};
 pub use crate::generators::edge_values::{
-    f64_edge_values, finite_f64_edge_values, i64_edge_values, u64_edge_values, with_none,
+    around, f64_edge_values, finite_f64_edge_values, i64_edge_values, u64_edge_values, with_none,
+};
+#[cfg(feature = "adversarial")]
+pub use crate::generators::identifiers::confusable_id;
+pub use crate::generators::identifiers::{alphanumeric_id, prefixed_id, regex_string};
+pub use crate::generators::numeric::{
+    bounded_f64, finite_f64, monotonic_f64s, non_negative_f64, positive_f64,
 };
-pub use crate::generators::identifiers::{alphanumeric_id, prefixed_id};
-pub use crate::generators::numeric::{bounded_f64, finite_f64, non_negative_f64, positive_f64};
 pub use crate::generators::temporal::{
-    monotonic_timestamps, timestamp_pair, valid_timestamp_millis,
+    clock_skew_timestamps, monotonic_timestamps, shuffled_timestamps, timestamp_pair,
+    valid_timestamp_millis,
 };
+pub use crate::generators::text::{balanced_brackets, unbalanced_brackets};
 
+pub use crate::assert_invariants;
 pub use crate::law::algebraic::{
-    assert_associative, assert_commutative, assert_distributive, assert_identity,
+    assert_associative, assert_associative_approx, assert_closed, assert_commutative,
+    assert_commutative_all, assert_commutative_approx, assert_distributive, assert_identity,
+    assert_inverse,
+};
+pub use crate::law::cache::assert_cache_consistent;
+pub use crate::law::collections::{
+    assert_chunks_cover, assert_disjoint, assert_frequency, assert_is_permutation_of,
+    assert_key_preserved, assert_no_adjacent_duplicates, assert_parallel_reduce_consistent,
+    assert_partitioned, assert_sort_deterministic, assert_sorted_by, assert_sorted_by_key,
+    assert_streams_equal, assert_subset,
 };
+pub use crate::law::crdt::assert_crdt_merge;
 pub use crate::law::invariants::{
-    assert_all_in_range, assert_approx_eq, assert_monotonic_increasing,
+    BoundsError, assert_all_in_range, assert_approx_eq, assert_approx_eq_relative,
+    assert_approx_eq_ulps, assert_bounded, assert_bounded_exclusive, assert_bounded_open,
+    assert_correlation, assert_monotone, assert_monotonic_increasing,
+    assert_monotonic_non_decreasing, assert_monotonic_non_increasing, assert_pure,
+    assert_rounds_half_to_even, assert_sample_stats, assert_unique_timestamps,
+    assert_within_percent, check_bounded,
 };
+pub use crate::law::panics::assert_panics_with;
+pub use crate::law::scheduling::assert_priority_respected;
 pub use crate::law::stateful::{
     assert_idempotent, assert_involutive, assert_state_invariant, assert_valid_state_sequence,
     assert_valid_state_transition,
@@ -46,12 +73,16 @@ pub use crate::law::tokio::task::{
 pub use crate::law::tokio::time::{
     BackoffObservation, BackoffProbe, IntervalDriftObservation, IntervalDriftProbe,
     TimeoutBehaviorProbe, TimeoutObservation, assert_backoff_bounds, assert_interval_no_drift,
-    assert_timeout_behavior,
+    assert_latency_slo, assert_timeout_behavior,
 };
+pub use crate::pbt_assert_all;
 
 #[cfg(feature = "serialization")]
 pub use crate::law::serialization::{assert_bincode_deterministic, assert_bincode_roundtrip};
-pub use crate::law::serialization::{assert_json_deterministic, assert_json_roundtrip};
+pub use crate::law::serialization::{
+    assert_compression_ratio, assert_json_deterministic, assert_json_roundtrip,
+    assert_lossless_transform, assert_parse_serialize_inverse, assert_roundtrip_preserves_order,
+};
 
 #[cfg(feature = "mutation")]
 pub use crate::mutation::{MutationConfig, MutationOutcome, MutationStatus, RunSnapshot};
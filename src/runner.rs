@@ -0,0 +1,69 @@
+//! Macro-free property checks for dynamically constructed strategies.
+
+use std::fmt::Debug;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::{TestCaseError, TestError, TestRunner};
+
+use crate::config::pbt_config;
+
+/// Run `law` against values drawn from `strategy`, outside the `proptest!` macro.
+///
+/// Uses [`pbt_config`] for case counts and shrinking. Panics with the shrunk counterexample when
+/// the law fails, or when too many cases are rejected.
+///
+/// ```
+/// use kitchensink_testing::prelude::*;
+/// use kitchensink_testing::runner::check_law;
+///
+/// let max = 10_u32;
+/// check_law(0..max, |v| {
+///     prop_assert!(v < max);
+///     Ok(())
+/// });
+/// ```
+pub fn check_law<S, F>(strategy: S, law: F)
+where
+    S: Strategy,
+    S::Value: Debug,
+    F: Fn(S::Value) -> Result<(), TestCaseError>,
+{
+    let mut runner = TestRunner::new(pbt_config());
+    match runner.run(&strategy, law) {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, value)) => {
+            panic!("law failed: {reason}; minimal failing input: {value:?}")
+        }
+        Err(TestError::Abort(reason)) => panic!("law check aborted: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::generators::temporal::monotonic_timestamps;
+    use crate::law::panics::assert_panics_with;
+
+    #[test]
+    fn dynamically_built_strategy_satisfies_law() {
+        for count in [1, 3, 8] {
+            check_law(monotonic_timestamps(count, 1, 1_000), |ts| {
+                prop_assert_eq!(ts.len(), count);
+                prop_assert!(ts.windows(2).all(|w| w[1] > w[0]));
+                Ok(())
+            });
+        }
+    }
+
+    #[test]
+    fn failing_law_reports_shrunk_counterexample() {
+        assert_panics_with("minimal failing input: 500", || {
+            check_law(0_u32..1_000, |v| {
+                prop_assert!(v < 500, "value {} too large", v);
+                Ok(())
+            });
+        });
+    }
+}
@@ -0,0 +1,89 @@
+//! Seeded strategy sampling and determinism assertions.
+
+use std::fmt::Debug;
+
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+/// Number of values drawn per runner by [`assert_strategy_deterministic`].
+pub const DETERMINISM_SAMPLES: usize = 64;
+
+/// Build a `TestRunner` whose RNG is fully determined by `seed`.
+pub fn seeded_runner(seed: u64) -> TestRunner {
+    let mut bytes = [0_u8; 32];
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&seed.to_le_bytes());
+    }
+    TestRunner::new_with_rng(
+        Config::default(),
+        TestRng::from_seed(RngAlgorithm::ChaCha, &bytes),
+    )
+}
+
+/// Draw `count` values from `strategy` using `runner`.
+pub fn draw_values<S>(strategy: &S, runner: &mut TestRunner, count: usize) -> Vec<S::Value>
+where
+    S: Strategy,
+{
+    (0..count)
+        .map(|_| {
+            strategy
+                .new_tree(runner)
+                .expect("strategy should generate a value")
+                .current()
+        })
+        .collect()
+}
+
+/// Assert two identically seeded runners draw the same value sequence from `strategy`.
+///
+/// Catches strategies that smuggle in nondeterminism (wall-clock time, global counters,
+/// hash-map iteration order). Reports the first differing sample index.
+pub fn assert_strategy_deterministic<S>(strategy: S, seed: u64)
+where
+    S: Strategy,
+    S::Value: PartialEq + Debug,
+{
+    let first = draw_values(&strategy, &mut seeded_runner(seed), DETERMINISM_SAMPLES);
+    let second = draw_values(&strategy, &mut seeded_runner(seed), DETERMINISM_SAMPLES);
+
+    for (index, (a, b)) in first.iter().zip(&second).enumerate() {
+        assert_eq!(
+            a, b,
+            "strategy is not deterministic under seed {seed}: sample {index} differs"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::generators::decimals::tick_aligned;
+    use crate::generators::temporal::monotonic_timestamps;
+
+    #[test]
+    fn domain_strategies_are_deterministic() {
+        assert_strategy_deterministic(tick_aligned(0.01, 100_000.0, 0.01), 7);
+        assert_strategy_deterministic(monotonic_timestamps(5, 1, 1_000), 7);
+    }
+
+    #[test]
+    fn different_seeds_produce_different_sequences() {
+        let strategy = any::<u64>();
+        let a = draw_values(&strategy, &mut seeded_runner(1), 8);
+        let b = draw_values(&strategy, &mut seeded_runner(2), 8);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "strategy is not deterministic under seed 7")]
+    fn hidden_counter_is_detected() {
+        static CALLS: AtomicU64 = AtomicU64::new(0);
+        let leaky = any::<u8>().prop_map(|v| u64::from(v) + CALLS.fetch_add(1, Ordering::SeqCst));
+        assert_strategy_deterministic(leaky, 7);
+    }
+}
@@ -21,6 +21,10 @@ fn fake_cargo_path(tmp: &Path) -> PathBuf {
         br#"#!/usr/bin/env sh
 set -e
 
+if [ -n "${RUST_PBT_FAKE_CARGO_ARGS_LOG:-}" ]; then
+  echo "$*" >> "$RUST_PBT_FAKE_CARGO_ARGS_LOG"
+fi
+
 if [ "$1" != "mutants" ]; then
   echo "unsupported command" >&2
   exit 1
@@ -28,6 +32,10 @@ fi
 
 shift
 
+while [ "$1" = "--in-place" ] || [ "$1" = "--no-times" ]; do
+  shift
+done
+
 if [ "$1" = "--help" ]; then
   echo "cargo mutants"
   echo "  --mutant <selector> execute one mutant"
@@ -37,6 +45,8 @@ fi
 if [ "$1" = "--list" ]; then
   echo "add"
   echo "sub"
+  echo "mul"
+  echo "div"
   exit 0
 fi
 
@@ -44,6 +54,11 @@ if [ "$1" = "--mutant" ]; then
   selector="$2"
   sleep "${RUST_PBT_FAKE_CARGO_SLEEP:-0.2}"
 
+  if [ "$selector" = "${RUST_PBT_FAKE_CARGO_ERROR:-}" ]; then
+    echo "rustc crashed while building mutant" >&2
+    exit 101
+  fi
+
   if [ "$selector" = "add" ]; then
     echo "mutant killed"
   else
@@ -222,4 +237,331 @@ fn e2e_cli_mutation_run_interrupt_resume_report() {
         serde_json::from_slice(&report_output.stdout).expect("report should be valid json");
     assert_eq!(report["run_id"].as_str(), Some(run_id.as_str()));
     assert_eq!(report["completed"].as_bool(), Some(true));
+
+    let metrics_output = run_cli_with_fake_cargo(
+        &[
+            "mutate",
+            "report",
+            run_id.as_str(),
+            "--format",
+            "prometheus",
+            "--run-root",
+            run_root
+                .to_str()
+                .expect("run_root path should be valid utf-8"),
+        ],
+        &fake_bin,
+    );
+    assert!(metrics_output.status.success());
+    let metrics = String::from_utf8_lossy(&metrics_output.stdout);
+    assert!(metrics.contains("# TYPE mutation_score gauge"));
+    assert!(metrics.contains(&format!("mutants_total{{run_id=\"{run_id}\"}} 4")));
+    assert!(metrics.ends_with("# EOF\n"));
+}
+
+#[test]
+fn e2e_cli_fail_on_error_exits_non_zero_for_error_mutants() {
+    let tmp = tempdir().expect("tempdir should be created");
+    let project_dir = tmp.path().join("project");
+    fs::create_dir_all(&project_dir).expect("project dir should be created");
+    let run_root = tmp.path().join("runs");
+    let fake_bin = fake_cargo_path(tmp.path());
+    let original_path = env::var("PATH").unwrap_or_else(|_| String::new());
+
+    let run = |fail_on_error: bool| {
+        let mut args = vec![
+            "mutate",
+            "run",
+            "--project",
+            project_dir
+                .to_str()
+                .expect("project path should be valid utf-8"),
+            "--run-root",
+            run_root
+                .to_str()
+                .expect("run_root path should be valid utf-8"),
+        ];
+        if fail_on_error {
+            args.push("--fail-on-error");
+        }
+        Command::new(env!("CARGO_BIN_EXE_cargo-pbt"))
+            .args(&args)
+            .env("PATH", format!("{}:{}", fake_bin.display(), original_path))
+            .env("RUST_PBT_FAKE_CARGO_SLEEP", "0")
+            .env("RUST_PBT_FAKE_CARGO_ERROR", "div")
+            .output()
+            .expect("cli command should run")
+    };
+
+    let lenient = run(false);
+    assert!(
+        lenient.status.success(),
+        "without the flag error mutants should not fail the command: {:?}",
+        String::from_utf8_lossy(&lenient.stderr)
+    );
+
+    // The previous run completed with survivors, so this invocation retests them; remove it so
+    // the gated run starts from scratch and re-executes the failing mutant.
+    fs::remove_dir_all(&run_root).expect("run root should be removed");
+    let strict = run(true);
+    assert_eq!(strict.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&strict.stderr);
+    assert!(
+        stderr.contains("1 mutant(s) ended in error status"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+#[test]
+fn e2e_cli_strict_status_and_report_fail_on_malformed_event_lines() {
+    let tmp = tempdir().expect("tempdir should be created");
+    let project_dir = tmp.path().join("project");
+    fs::create_dir_all(&project_dir).expect("project dir should be created");
+    let run_root = tmp.path().join("runs");
+    let run_root_arg = run_root
+        .to_str()
+        .expect("run_root path should be valid utf-8");
+    let fake_bin = fake_cargo_path(tmp.path());
+
+    let run_output = run_cli_with_fake_cargo(
+        &[
+            "mutate",
+            "run",
+            "--project",
+            project_dir
+                .to_str()
+                .expect("project path should be valid utf-8"),
+            "--run-root",
+            run_root_arg,
+        ],
+        &fake_bin,
+    );
+    assert!(run_output.status.success());
+    let run_id = run_id_from_output(&run_output.stdout);
+
+    let mut events = fs::OpenOptions::new()
+        .append(true)
+        .open(run_root.join(&run_id).join("events.jsonl"))
+        .expect("event log should open for append");
+    writeln!(events, "{{not valid json").expect("corrupt line should be appended");
+    drop(events);
+
+    let status = |strict: bool| {
+        let mut args = vec!["mutate", "status", &run_id, "--run-root", run_root_arg];
+        if strict {
+            args.push("--strict");
+        }
+        run_cli_with_fake_cargo(&args, &fake_bin)
+    };
+    let lenient = status(false);
+    assert!(lenient.status.success());
+    assert!(String::from_utf8_lossy(&lenient.stdout).contains("malformed lines: 1"));
+
+    let strict = status(true);
+    assert_eq!(strict.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&strict.stdout).contains("malformed lines: 1"));
+    assert!(
+        String::from_utf8_lossy(&strict.stderr).contains("1 malformed event line(s)"),
+        "unexpected stderr: {}",
+        String::from_utf8_lossy(&strict.stderr)
+    );
+
+    let report = run_cli_with_fake_cargo(
+        &[
+            "mutate",
+            "report",
+            &run_id,
+            "--format",
+            "json",
+            "--run-root",
+            run_root_arg,
+            "--strict",
+        ],
+        &fake_bin,
+    );
+    assert_eq!(report.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&report.stderr).contains("malformed lines: 1"));
+    let _: serde_json::Value =
+        serde_json::from_slice(&report.stdout).expect("report stdout should stay valid json");
+}
+
+#[test]
+fn e2e_cli_status_exit_code_maps_run_state() {
+    let tmp = tempdir().expect("tempdir should be created");
+    let project_dir = tmp.path().join("project");
+    fs::create_dir_all(&project_dir).expect("project dir should be created");
+    let project_arg = project_dir
+        .to_str()
+        .expect("project path should be valid utf-8");
+    let fake_bin = fake_cargo_path(tmp.path());
+    let original_path = env::var("PATH").unwrap_or_else(|_| String::new());
+
+    let run = |name: &str, filter: Option<&str>, error_selector: &str| {
+        let run_root = tmp.path().join(name);
+        let run_root_arg = run_root
+            .to_str()
+            .expect("run_root path should be valid utf-8")
+            .to_string();
+        let mut args = vec!["mutate", "run", "--project", project_arg];
+        args.extend(["--run-root", &run_root_arg]);
+        if let Some(filter) = filter {
+            args.extend(["--filter", filter]);
+        }
+        let output = Command::new(env!("CARGO_BIN_EXE_cargo-pbt"))
+            .args(&args)
+            .env("PATH", format!("{}:{}", fake_bin.display(), original_path))
+            .env("RUST_PBT_FAKE_CARGO_SLEEP", "0")
+            .env("RUST_PBT_FAKE_CARGO_ERROR", error_selector)
+            .output()
+            .expect("cli command should run");
+        assert!(output.status.success());
+        (run_root, run_id_from_output(&output.stdout))
+    };
+    let status_code = |run_root: &Path, run_id: &str| {
+        run_cli_with_fake_cargo(
+            &[
+                "mutate",
+                "status",
+                run_id,
+                "--run-root",
+                run_root
+                    .to_str()
+                    .expect("run_root path should be valid utf-8"),
+                "--exit-code",
+            ],
+            &fake_bin,
+        )
+        .status
+        .code()
+    };
+
+    let (clean_root, clean_id) = run("clean", Some("add"), "");
+    assert_eq!(status_code(&clean_root, &clean_id), Some(0));
+
+    let (survivor_root, survivor_id) = run("survivors", None, "");
+    assert_eq!(status_code(&survivor_root, &survivor_id), Some(3));
+
+    let (error_root, error_id) = run("errors", None, "div");
+    assert_eq!(status_code(&error_root, &error_id), Some(4));
+
+    let events = clean_root.join(&clean_id).join("events.jsonl");
+    let log = fs::read_to_string(&events).expect("event log should be readable");
+    let truncated: String = log
+        .lines()
+        .filter(|line| !line.contains("\"event\":\"run_completed\""))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert_ne!(truncated, log, "clean run should have a completion event");
+    fs::write(&events, truncated).expect("event log should be rewritten");
+    assert_eq!(status_code(&clean_root, &clean_id), Some(5));
+}
+
+#[test]
+fn e2e_cli_package_flag_is_forwarded_to_cargo_mutants() {
+    let tmp = tempdir().expect("tempdir should be created");
+    let project_dir = tmp.path().join("project");
+    fs::create_dir_all(&project_dir).expect("project dir should be created");
+    let run_root = tmp.path().join("runs");
+    let args_log = tmp.path().join("cargo-args.log");
+    let fake_bin = fake_cargo_path(tmp.path());
+    let original_path = env::var("PATH").unwrap_or_else(|_| String::new());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-pbt"))
+        .args([
+            "mutate",
+            "run",
+            "--project",
+            project_dir
+                .to_str()
+                .expect("project path should be valid utf-8"),
+            "--run-root",
+            run_root
+                .to_str()
+                .expect("run_root path should be valid utf-8"),
+            "--package",
+            "core",
+            "--package",
+            "cli",
+        ])
+        .env("PATH", format!("{}:{}", fake_bin.display(), original_path))
+        .env("RUST_PBT_FAKE_CARGO_SLEEP", "0")
+        .env("RUST_PBT_FAKE_CARGO_ARGS_LOG", &args_log)
+        .output()
+        .expect("cli command should run");
+    assert!(
+        output.status.success(),
+        "run should succeed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let log = fs::read_to_string(&args_log).expect("fake cargo should log its args");
+    let list_calls: Vec<&str> = log.lines().filter(|l| l.contains("--list")).collect();
+    let mutant_calls: Vec<&str> = log.lines().filter(|l| l.contains("--mutant ")).collect();
+    assert_eq!(list_calls.len(), 1, "unexpected invocations:\n{log}");
+    assert_eq!(mutant_calls.len(), 4, "unexpected invocations:\n{log}");
+    for call in list_calls.iter().chain(&mutant_calls) {
+        assert!(
+            call.ends_with("--package core --package cli"),
+            "packages not forwarded: {call}"
+        );
+    }
+}
+
+#[test]
+fn e2e_cli_extra_and_test_args_are_forwarded_with_separator() {
+    let tmp = tempdir().expect("tempdir should be created");
+    let project_dir = tmp.path().join("project");
+    fs::create_dir_all(&project_dir).expect("project dir should be created");
+    let run_root = tmp.path().join("runs");
+    let args_log = tmp.path().join("cargo-args.log");
+    let fake_bin = fake_cargo_path(tmp.path());
+    let original_path = env::var("PATH").unwrap_or_else(|_| String::new());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-pbt"))
+        .args([
+            "mutate",
+            "run",
+            "--project",
+            project_dir
+                .to_str()
+                .expect("project path should be valid utf-8"),
+            "--run-root",
+            run_root
+                .to_str()
+                .expect("run_root path should be valid utf-8"),
+            "--cargo-arg",
+            "--no-default-features",
+            "--cargo-arg=--features=fast",
+            "--",
+            "--test-threads=1",
+        ])
+        .env("PATH", format!("{}:{}", fake_bin.display(), original_path))
+        .env("RUST_PBT_FAKE_CARGO_SLEEP", "0")
+        .env("RUST_PBT_FAKE_CARGO_ARGS_LOG", &args_log)
+        .output()
+        .expect("cli command should run");
+    assert!(
+        output.status.success(),
+        "run should succeed: {:?}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let log = fs::read_to_string(&args_log).expect("fake cargo should log its args");
+    let list_call = log
+        .lines()
+        .find(|l| l.contains("--list"))
+        .expect("listing should be invoked");
+    assert!(
+        list_call.ends_with("--list --no-default-features --features=fast"),
+        "extra args not forwarded to listing: {list_call}"
+    );
+
+    let mutant_calls: Vec<&str> = log.lines().filter(|l| l.contains("--mutant ")).collect();
+    assert_eq!(mutant_calls.len(), 4, "unexpected invocations:\n{log}");
+    for call in mutant_calls {
+        assert!(
+            call.ends_with("--no-default-features --features=fast -- --test-threads=1"),
+            "extra/test args not forwarded: {call}"
+        );
+    }
 }